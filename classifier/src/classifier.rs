@@ -0,0 +1,121 @@
+use bevy::prelude::*;
+use bevy_turborand::{rng::Rng, DelegatedRng, GlobalRng, SeededCore, TurboRand};
+use heep::{Perceptron, WorldBounds};
+
+/// Random points labelled by which side of [`ClassifierPlugin::line`] they
+/// fall, in coordinates normalized to `[-1, 1]`.
+#[derive(Resource)]
+struct TrainingSet {
+    points: Vec<(Vec2, f32)>,
+    /// Index of the next point to train on.
+    next: usize,
+}
+
+#[derive(Resource)]
+struct Classifier(Perceptron);
+
+/// Trains a [`Perceptron`] to tell points above a line from points below
+/// it, a few points per frame. Points are drawn green once classified
+/// correctly and red otherwise, with the learned boundary in yellow.
+/// Space resets the weights.
+pub struct ClassifierPlugin;
+
+impl Plugin for ClassifierPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(Startup, Self::setup);
+        app.add_systems(Update, (Self::reset, Self::train, Self::draw).chain());
+    }
+}
+
+impl ClassifierPlugin {
+    const POINTS: usize = 500;
+    const TRAINING_PER_FRAME: usize = 5;
+    const LEARNING_RATE: f32 = 0.005;
+    const POINT_RADIUS: f32 = 0.8;
+    const CORRECT: Color = Color::srgb(0.3, 0.8, 0.4);
+    const WRONG: Color = Color::srgb(0.9, 0.3, 0.3);
+    const LINE: Color = Color::WHITE;
+    const GUESS: Color = Color::srgb(0.95, 0.85, 0.3);
+
+    fn line(x: f32) -> f32 {
+        0.6 * x - 0.1
+    }
+
+    fn setup(mut commands: Commands, mut global_rng: ResMut<GlobalRng>) {
+        let rng = Rng::with_seed(global_rng.u64(..));
+        let points = (0..Self::POINTS)
+            .map(|_| {
+                let point = Vec2::new(rng.f32_normalized(), rng.f32_normalized());
+                let label = if point.y > Self::line(point.x) {
+                    1.
+                } else {
+                    -1.
+                };
+                (point, label)
+            })
+            .collect();
+
+        commands.insert_resource(TrainingSet { points, next: 0 });
+        commands.insert_resource(Classifier(Perceptron::new(2, Self::LEARNING_RATE, &rng)));
+    }
+
+    fn reset(
+        keyboard_input: Res<ButtonInput<KeyCode>>,
+        mut global_rng: ResMut<GlobalRng>,
+        mut classifier: ResMut<Classifier>,
+    ) {
+        if keyboard_input.just_pressed(KeyCode::Space) {
+            let rng = Rng::with_seed(global_rng.u64(..));
+            classifier.0 = Perceptron::new(2, Self::LEARNING_RATE, &rng);
+        }
+    }
+
+    fn train(mut training_set: ResMut<TrainingSet>, mut classifier: ResMut<Classifier>) {
+        for _ in 0..Self::TRAINING_PER_FRAME {
+            let (point, label) = training_set.points[training_set.next];
+            classifier.0.train(&point.to_array(), label);
+            training_set.next = (training_set.next + 1) % training_set.points.len();
+        }
+    }
+
+    fn draw(
+        training_set: Res<TrainingSet>,
+        classifier: Res<Classifier>,
+        bounds: Res<WorldBounds>,
+        mut gizmos: Gizmos,
+    ) {
+        let bounds = bounds.0;
+        if bounds.is_empty() {
+            return;
+        }
+        let to_world = |point: Vec2| bounds.center() + point * bounds.half_size();
+
+        for &(point, label) in &training_set.points {
+            let color = if classifier.0.predict(&point.to_array()) == label {
+                Self::CORRECT
+            } else {
+                Self::WRONG
+            };
+            gizmos.circle_2d(to_world(point), Self::POINT_RADIUS, color);
+        }
+
+        gizmos.line_2d(
+            to_world(Vec2::new(-1., Self::line(-1.))),
+            to_world(Vec2::new(1., Self::line(1.))),
+            Self::LINE,
+        );
+
+        // The boundary is where w0 x + w1 y + b = 0.
+        let perceptron = &classifier.0;
+        if let [w0, w1] = perceptron.weights[..] {
+            if w1 != 0. {
+                let guess = |x: f32| -(w0 * x + perceptron.bias) / w1;
+                gizmos.line_2d(
+                    to_world(Vec2::new(-1., guess(-1.))),
+                    to_world(Vec2::new(1., guess(1.))),
+                    Self::GUESS,
+                );
+            }
+        }
+    }
+}
@@ -0,0 +1,11 @@
+use bevy::prelude::*;
+use heep::Sketch;
+
+mod classifier;
+
+fn main() {
+    App::new()
+        .add_plugins(Sketch::new("heep - Perceptron").with_background(Color::srgb(0.1, 0.1, 0.15)))
+        .add_plugins(classifier::ClassifierPlugin)
+        .run();
+}
@@ -0,0 +1,156 @@
+use std::{
+    fs::{self, File},
+    io::{self, BufWriter, Write},
+    ops::RangeInclusive,
+    path::{Path, PathBuf},
+};
+
+use bevy::prelude::*;
+
+use crate::{
+    creature::{Creature, Genes},
+    food::Food,
+};
+
+/// Overrides the directory the census CSV files are written to.
+const LOG_DIR_VAR: &str = "ECOSYSTEM_LOG_DIR";
+
+/// Directory the census CSV files are written to: `$ECOSYSTEM_LOG_DIR`, or
+/// `logs` in the working directory.
+fn log_dir() -> PathBuf {
+    std::env::var_os(LOG_DIR_VAR).map_or_else(|| PathBuf::from("logs"), PathBuf::from)
+}
+
+/// Open census files. `population.csv` gets one row per census with counts
+/// and trait means; `traits.csv` gets one row per histogram bin per trait.
+#[derive(Resource)]
+struct CensusLog {
+    population: BufWriter<File>,
+    traits: BufWriter<File>,
+}
+
+impl CensusLog {
+    fn create(dir: &Path) -> io::Result<Self> {
+        fs::create_dir_all(dir)?;
+        let mut population = BufWriter::new(File::create(dir.join("population.csv"))?);
+        let mut traits = BufWriter::new(File::create(dir.join("traits.csv"))?);
+
+        writeln!(
+            population,
+            "time,creatures,food,mean_size,mean_speed,mean_health,mean_age"
+        )?;
+        writeln!(traits, "time,trait,bin_min,bin_max,count")?;
+        Ok(Self { population, traits })
+    }
+}
+
+#[derive(Resource)]
+struct CensusTimer(Timer);
+
+/// Periodically logs the population and histograms of its genes to CSV.
+pub struct CensusPlugin;
+
+impl Plugin for CensusPlugin {
+    fn build(&self, app: &mut App) {
+        let dir = log_dir();
+        match CensusLog::create(&dir) {
+            Ok(log) => {
+                app.insert_resource(log);
+            }
+            Err(error) => error!(
+                "census disabled, could not create {} (set {LOG_DIR_VAR} to log elsewhere): {error}",
+                dir.display()
+            ),
+        }
+        app.insert_resource(CensusTimer(Timer::from_seconds(
+            Self::INTERVAL_SECONDS,
+            TimerMode::Repeating,
+        )));
+        app.add_systems(Update, Self::record.run_if(resource_exists::<CensusLog>));
+    }
+}
+
+impl CensusPlugin {
+    const INTERVAL_SECONDS: f32 = 1.;
+    const BINS: usize = 10;
+
+    fn histogram(values: impl Iterator<Item = f32>, range: &RangeInclusive<f32>) -> Vec<usize> {
+        let width = (range.end() - range.start()) / Self::BINS as f32;
+        let mut bins = vec![0; Self::BINS];
+        for value in values {
+            let bin = ((value - range.start()) / width) as usize;
+            bins[bin.min(Self::BINS - 1)] += 1;
+        }
+        bins
+    }
+
+    fn write(
+        log: &mut CensusLog,
+        time: f32,
+        creatures: &[(&Creature, &Genes)],
+        food: usize,
+    ) -> io::Result<()> {
+        let count = creatures.len();
+        let mean = |f: &dyn Fn(&(&Creature, &Genes)) -> f32| {
+            creatures.iter().map(f).sum::<f32>() / count.max(1) as f32
+        };
+
+        writeln!(
+            log.population,
+            "{time:.2},{count},{food},{:.3},{:.3},{:.3},{:.3}",
+            mean(&|(_, genes)| genes.size),
+            mean(&|(_, genes)| genes.speed),
+            mean(&|(creature, _)| creature.health),
+            mean(&|(creature, _)| creature.age),
+        )?;
+
+        let genes = |gene: fn(&Genes) -> f32| creatures.iter().map(move |(_, genes)| gene(genes));
+        for (name, range, values) in [
+            (
+                "size",
+                Genes::SIZE,
+                Self::histogram(genes(|genes| genes.size), &Genes::SIZE),
+            ),
+            (
+                "speed",
+                Genes::SPEED,
+                Self::histogram(genes(|genes| genes.speed), &Genes::SPEED),
+            ),
+        ] {
+            let width = (range.end() - range.start()) / Self::BINS as f32;
+            for (bin, count) in values.into_iter().enumerate() {
+                let min = range.start() + bin as f32 * width;
+                writeln!(
+                    log.traits,
+                    "{time:.2},{name},{min:.3},{:.3},{count}",
+                    min + width
+                )?;
+            }
+        }
+
+        log.population.flush()?;
+        log.traits.flush()
+    }
+
+    fn record(
+        time: Res<Time>,
+        mut timer: ResMut<CensusTimer>,
+        mut log: ResMut<CensusLog>,
+        creatures: Query<(&Creature, &Genes)>,
+        food: Query<(), With<Food>>,
+    ) {
+        if !timer.0.tick(time.delta()).just_finished() {
+            return;
+        }
+
+        let creatures: Vec<_> = creatures.iter().collect();
+        if let Err(error) = Self::write(
+            &mut log,
+            time.elapsed_seconds(),
+            &creatures,
+            food.iter().len(),
+        ) {
+            error!("could not write census: {error}");
+        }
+    }
+}
@@ -0,0 +1,238 @@
+use std::{collections::HashSet, ops::RangeInclusive};
+
+use bevy::{prelude::*, sprite::MaterialMesh2dBundle};
+use bevy_turborand::{DelegatedRng, GlobalRng};
+use heep::{
+    Behavior, BodyBundle, EdgeBehavior, Forces, Layer, PhysicsSet, Position, SpatialIndex,
+    Steering, Vehicle, Velocity, Wander,
+};
+
+use crate::food::{Food, FoodAssets, FoodPlugin};
+
+/// Heritable traits, passed on with small mutations.
+#[derive(Component, Clone, Copy)]
+pub struct Genes {
+    /// Radius of the creature. Bigger creatures store more health and reach
+    /// food from further away, but burn health faster.
+    pub size: f32,
+    /// Top speed. Faster creatures find more food, but burn health faster.
+    pub speed: f32,
+}
+
+impl Genes {
+    pub const SIZE: RangeInclusive<f32> = 1.0..=6.0;
+    pub const SPEED: RangeInclusive<f32> = 5.0..=40.0;
+
+    fn random(rng: &mut GlobalRng) -> Self {
+        let pick =
+            |range: &RangeInclusive<f32>, t: f32| range.start() + (range.end() - range.start()) * t;
+        Self {
+            size: pick(&Self::SIZE, rng.f32()),
+            speed: pick(&Self::SPEED, rng.f32()),
+        }
+    }
+
+    /// Shifts each gene by up to a tenth of its range with probability
+    /// `rate`.
+    fn mutated(self, rate: f64, rng: &mut GlobalRng) -> Self {
+        let mut mutate = |value: f32, range: RangeInclusive<f32>| {
+            if rng.chance(rate) {
+                let shift = rng.f32_normalized() * (range.end() - range.start()) * 0.1;
+                (value + shift).clamp(*range.start(), *range.end())
+            } else {
+                value
+            }
+        };
+        Self {
+            size: mutate(self.size, Self::SIZE),
+            speed: mutate(self.speed, Self::SPEED),
+        }
+    }
+
+    pub fn max_health(&self) -> f32 {
+        self.size * 40.
+    }
+
+    /// Health burned per second.
+    fn metabolism(&self) -> f32 {
+        1. + self.size * self.size * 0.15 + self.speed * self.speed * 0.004
+    }
+}
+
+#[derive(Component)]
+pub struct Creature {
+    pub health: f32,
+    /// Seconds since birth.
+    pub age: f32,
+}
+
+#[derive(Resource)]
+struct CreatureAssets {
+    mesh: Handle<Mesh>,
+    material: Handle<ColorMaterial>,
+}
+
+/// Creatures wander, seek out nearby food and lose health over time. Well
+/// fed creatures split in two with mutated genes; starved ones die and
+/// leave food behind.
+pub struct CreaturePlugin;
+
+impl Plugin for CreaturePlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(Startup, Self::setup);
+        app.add_systems(FixedUpdate, Self::forage.before(PhysicsSet));
+        app.add_systems(
+            FixedUpdate,
+            (Self::metabolize, Self::eat, Self::reproduce, Self::die)
+                .chain()
+                .after(PhysicsSet),
+        );
+    }
+}
+
+impl CreaturePlugin {
+    const INITIAL_POPULATION: usize = 40;
+    const SPAWN_HALF_SIZE: Vec2 = Vec2::new(100., 60.);
+    const PERCEPTION: f32 = 20.;
+    const FORAGE_WEIGHT: f32 = 2.;
+    /// Fraction of max health above which a creature may reproduce.
+    const FERTILE_HEALTH: f32 = 0.8;
+    const REPRODUCTION_PER_SECOND: f64 = 0.2;
+    const MUTATION_RATE: f64 = 0.3;
+
+    fn setup(
+        mut commands: Commands,
+        mut meshes: ResMut<Assets<Mesh>>,
+        mut materials: ResMut<Assets<ColorMaterial>>,
+        mut rng: ResMut<GlobalRng>,
+    ) {
+        let assets = CreatureAssets {
+            mesh: meshes.add(Circle { radius: 1. }),
+            material: materials.add(Color::srgba(0.9, 0.6, 0.3, 0.8)),
+        };
+
+        for _ in 0..Self::INITIAL_POPULATION {
+            let genes = Genes::random(&mut rng);
+            let position =
+                Vec2::new(rng.f32_normalized(), rng.f32_normalized()) * Self::SPAWN_HALF_SIZE;
+            Self::spawn(
+                &mut commands,
+                &assets,
+                genes,
+                position,
+                genes.max_health() / 2.,
+            );
+        }
+
+        commands.insert_resource(assets);
+    }
+
+    fn spawn(
+        commands: &mut Commands,
+        assets: &CreatureAssets,
+        genes: Genes,
+        position: Vec2,
+        health: f32,
+    ) {
+        commands.spawn((
+            MaterialMesh2dBundle {
+                mesh: assets.mesh.clone().into(),
+                material: assets.material.clone(),
+                transform: Transform::from_scale(Vec3::splat(genes.size)),
+                ..Default::default()
+            },
+            Creature { health, age: 0. },
+            genes,
+            BodyBundle {
+                position: Position(position),
+                ..Default::default()
+            },
+            Vehicle::new(genes.speed, genes.speed * 2.),
+            Steering::default().with(Behavior::Wander(Wander::default()), 1.),
+            EdgeBehavior::Wrap,
+            Layer(1.),
+        ));
+    }
+
+    fn forage(
+        index: Res<SpatialIndex>,
+        food: Query<&Position, With<Food>>,
+        mut creatures: Query<(&Genes, &Vehicle, &Position, &Velocity, &mut Forces)>,
+    ) {
+        for (genes, vehicle, position, velocity, mut forces) in &mut creatures {
+            let mut nearest: Option<Vec2> = None;
+            index.for_each_in_radius(position.0, Self::PERCEPTION + genes.size, |entry| {
+                if let Ok(food) = food.get(entry.entity) {
+                    if nearest.is_none_or(|best| {
+                        food.0.distance_squared(position.0) < best.distance_squared(position.0)
+                    }) {
+                        nearest = Some(food.0);
+                    }
+                }
+            });
+
+            if let Some(target) = nearest {
+                forces.apply_force(
+                    vehicle.seek(position.0, velocity.0, target) * Self::FORAGE_WEIGHT,
+                );
+            }
+        }
+    }
+
+    fn metabolize(time: Res<Time>, mut creatures: Query<(&mut Creature, &Genes)>) {
+        let dt = time.delta_seconds();
+        for (mut creature, genes) in &mut creatures {
+            creature.health -= genes.metabolism() * dt;
+            creature.age += dt;
+        }
+    }
+
+    fn eat(
+        mut commands: Commands,
+        index: Res<SpatialIndex>,
+        food: Query<&Food>,
+        mut creatures: Query<(&mut Creature, &Genes, &Position)>,
+    ) {
+        let mut eaten = HashSet::new();
+        for (mut creature, genes, position) in &mut creatures {
+            index.for_each_in_radius(position.0, genes.size, |entry| {
+                if let Ok(food) = food.get(entry.entity) {
+                    if eaten.insert(entry.entity) {
+                        creature.health = (creature.health + food.energy).min(genes.max_health());
+                        commands.entity(entry.entity).despawn();
+                    }
+                }
+            });
+        }
+    }
+
+    fn reproduce(
+        mut commands: Commands,
+        time: Res<Time>,
+        mut rng: ResMut<GlobalRng>,
+        assets: Res<CreatureAssets>,
+        mut creatures: Query<(&mut Creature, &Genes, &Position)>,
+    ) {
+        let chance = Self::REPRODUCTION_PER_SECOND * time.delta_seconds_f64();
+        for (mut creature, genes, position) in &mut creatures {
+            if creature.health > genes.max_health() * Self::FERTILE_HEALTH && rng.chance(chance) {
+                creature.health /= 2.;
+                let child = genes.mutated(Self::MUTATION_RATE, &mut rng);
+                Self::spawn(&mut commands, &assets, child, position.0, creature.health);
+            }
+        }
+    }
+
+    fn die(
+        mut commands: Commands,
+        food_assets: Res<FoodAssets>,
+        creatures: Query<(Entity, &Creature, &Position)>,
+    ) {
+        for (entity, creature, position) in &creatures {
+            if creature.health <= 0. {
+                commands.entity(entity).despawn();
+                FoodPlugin::spawn(&mut commands, &food_assets, position.0, FoodPlugin::ENERGY);
+            }
+        }
+    }
+}
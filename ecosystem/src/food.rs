@@ -0,0 +1,85 @@
+use bevy::{prelude::*, sprite::MaterialMesh2dBundle};
+use bevy_turborand::{DelegatedRng, GlobalRng};
+use heep::{Position, WorldBounds};
+
+/// Health a creature regains by eating this.
+#[derive(Component)]
+pub struct Food {
+    pub energy: f32,
+}
+
+#[derive(Resource)]
+pub struct FoodAssets {
+    mesh: Handle<Mesh>,
+    material: Handle<ColorMaterial>,
+}
+
+#[derive(Resource)]
+struct GrowthTimer(Timer);
+
+/// Grows food at random spots in the world up to a fixed amount.
+pub struct FoodPlugin;
+
+impl Plugin for FoodPlugin {
+    fn build(&self, app: &mut App) {
+        app.insert_resource(GrowthTimer(Timer::from_seconds(
+            1. / Self::GROWTH_PER_SECOND,
+            TimerMode::Repeating,
+        )));
+        app.add_systems(Startup, Self::setup);
+        app.add_systems(FixedUpdate, Self::grow);
+    }
+}
+
+impl FoodPlugin {
+    const GROWTH_PER_SECOND: f32 = 12.;
+    const MAX_FOOD: usize = 250;
+    pub const ENERGY: f32 = 25.;
+    const RADIUS: f32 = 0.6;
+
+    fn setup(
+        mut commands: Commands,
+        mut meshes: ResMut<Assets<Mesh>>,
+        mut materials: ResMut<Assets<ColorMaterial>>,
+    ) {
+        commands.insert_resource(FoodAssets {
+            mesh: meshes.add(Circle {
+                radius: Self::RADIUS,
+            }),
+            material: materials.add(Color::srgb(0.5, 0.8, 0.3)),
+        });
+    }
+
+    pub fn spawn(commands: &mut Commands, assets: &FoodAssets, position: Vec2, energy: f32) {
+        commands.spawn((
+            MaterialMesh2dBundle {
+                mesh: assets.mesh.clone().into(),
+                material: assets.material.clone(),
+                ..Default::default()
+            },
+            Food { energy },
+            Position(position),
+        ));
+    }
+
+    fn grow(
+        mut commands: Commands,
+        time: Res<Time>,
+        mut timer: ResMut<GrowthTimer>,
+        mut rng: ResMut<GlobalRng>,
+        bounds: Res<WorldBounds>,
+        assets: Res<FoodAssets>,
+        food: Query<(), With<Food>>,
+    ) {
+        let grown = timer.0.tick(time.delta()).times_finished_this_tick() as usize;
+        if bounds.0.is_empty() {
+            return;
+        }
+
+        for _ in 0..grown.min(Self::MAX_FOOD.saturating_sub(food.iter().len())) {
+            let position = bounds.0.center()
+                + Vec2::new(rng.f32_normalized(), rng.f32_normalized()) * bounds.0.half_size();
+            Self::spawn(&mut commands, &assets, position, Self::ENERGY);
+        }
+    }
+}
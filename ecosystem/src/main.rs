@@ -0,0 +1,19 @@
+use bevy::prelude::*;
+use heep::{Sketch, SpatialIndexPlugin, SteeringPlugin};
+
+mod census;
+mod creature;
+mod food;
+
+fn main() {
+    App::new()
+        .add_plugins(Sketch::new("heep - Ecosystem").with_world_height(150.))
+        .add_plugins((
+            SteeringPlugin,
+            SpatialIndexPlugin,
+            food::FoodPlugin,
+            creature::CreaturePlugin,
+            census::CensusPlugin,
+        ))
+        .run();
+}
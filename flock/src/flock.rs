@@ -0,0 +1,78 @@
+use std::f32::consts::PI;
+
+use bevy::{prelude::*, sprite::MaterialMesh2dBundle};
+use bevy_turborand::{DelegatedRng, GlobalRng};
+use heep::{
+    BodyBundle, Boid, EdgeBehavior, FaceVelocity, Flock, Position, Rotation, Vehicle, Velocity,
+};
+
+pub struct FlockPlugin;
+
+impl Plugin for FlockPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(Startup, Self::setup);
+    }
+}
+
+impl FlockPlugin {
+    const BOIDS_PER_FLOCK: usize = 1500;
+    const SPAWN_HALF_SIZE: Vec2 = Vec2::new(170., 95.);
+    const MAX_SPEED: f32 = 20.;
+    const MAX_FORCE: f32 = 30.;
+    const SIZE: f32 = 1.2;
+
+    fn setup(
+        mut commands: Commands,
+        mut meshes: ResMut<Assets<Mesh>>,
+        mut materials: ResMut<Assets<ColorMaterial>>,
+        mut rng: ResMut<GlobalRng>,
+    ) {
+        let mesh = meshes.add(Triangle2d::new(
+            Vec2::new(Self::SIZE * 1.5, 0.),
+            Vec2::new(-Self::SIZE, Self::SIZE * 0.8),
+            Vec2::new(-Self::SIZE, -Self::SIZE * 0.8),
+        ));
+
+        let flocks = [
+            (Flock::default(), Color::srgb(0.9, 0.9, 0.6)),
+            (
+                Flock {
+                    separation_weight: 2.,
+                    cohesion_weight: 0.5,
+                    field_of_view: PI,
+                    ..Default::default()
+                },
+                Color::srgb(0.9, 0.5, 0.4),
+            ),
+        ];
+
+        for (flock, color) in flocks {
+            let flock = commands.spawn(flock).id();
+            let material = materials.add(color);
+
+            for _ in 0..Self::BOIDS_PER_FLOCK {
+                let position =
+                    Vec2::new(rng.f32_normalized(), rng.f32_normalized()) * Self::SPAWN_HALF_SIZE;
+                let velocity = Vec2::from_angle(rng.f32() * 2. * PI) * Self::MAX_SPEED;
+
+                commands.spawn((
+                    MaterialMesh2dBundle {
+                        mesh: mesh.clone().into(),
+                        material: material.clone(),
+                        ..Default::default()
+                    },
+                    BodyBundle {
+                        position: Position(position),
+                        velocity: Velocity(velocity),
+                        ..Default::default()
+                    },
+                    Boid { flock },
+                    Vehicle::new(Self::MAX_SPEED, Self::MAX_FORCE),
+                    EdgeBehavior::Wrap,
+                    Rotation::default(),
+                    FaceVelocity,
+                ));
+            }
+        }
+    }
+}
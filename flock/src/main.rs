@@ -0,0 +1,11 @@
+use bevy::prelude::*;
+use heep::{FlockingPlugin, Sketch, SteeringPlugin};
+
+mod flock;
+
+fn main() {
+    App::new()
+        .add_plugins(Sketch::new("heep - Flock").with_world_height(200.))
+        .add_plugins((FlockingPlugin, SteeringPlugin, flock::FlockPlugin))
+        .run();
+}
@@ -0,0 +1,129 @@
+use std::f32::consts::{FRAC_PI_2, TAU};
+
+use bevy::prelude::*;
+use heep::Segment;
+
+/// Koch curve between `start` and `end`: each level replaces every segment
+/// with four a third as long, bumped out to the left.
+pub fn koch_curve(start: Vec2, end: Vec2, depth: usize) -> Vec<Segment> {
+    let mut segments = vec![Segment {
+        start,
+        end,
+        depth: 0,
+    }];
+
+    for level in 1..=depth {
+        segments = segments
+            .into_iter()
+            .flat_map(|Segment { start, end, .. }| {
+                let third = (end - start) / 3.;
+                let a = start + third;
+                let b = a + Vec2::from_angle(TAU / 6.).rotate(third);
+                let c = start + third * 2.;
+                [(start, a), (a, b), (b, c), (c, end)].map(|(start, end)| Segment {
+                    start,
+                    end,
+                    depth: level,
+                })
+            })
+            .collect();
+    }
+
+    segments
+}
+
+/// Koch snowflake: three Koch curves around an equilateral triangle
+/// inscribed in a circle of `radius`.
+pub fn koch_snowflake(center: Vec2, radius: f32, depth: usize) -> Vec<Segment> {
+    let corner = |i: usize| center + Vec2::from_angle(FRAC_PI_2 - i as f32 * TAU / 3.) * radius;
+    (0..3)
+        .flat_map(|i| koch_curve(corner(i), corner(i + 1), depth))
+        .collect()
+}
+
+/// Cantor set: one line per kept interval, with each level drawn `spacing`
+/// below the last and the middle third removed.
+pub fn cantor_set(start: Vec2, width: f32, spacing: f32, depth: usize) -> Vec<Segment> {
+    let mut intervals = vec![(start.x, start.x + width)];
+    let mut segments = Vec::new();
+
+    for level in 0..=depth {
+        let y = start.y - level as f32 * spacing;
+        segments.extend(intervals.iter().map(|&(left, right)| Segment {
+            start: Vec2::new(left, y),
+            end: Vec2::new(right, y),
+            depth: level,
+        }));
+
+        intervals = intervals
+            .into_iter()
+            .flat_map(|(left, right)| {
+                let third = (right - left) / 3.;
+                [(left, left + third), (right - third, right)]
+            })
+            .collect();
+    }
+
+    segments
+}
+
+/// Sierpinski triangle as the filled triangles left after removing the
+/// middle quarter `depth` times.
+pub fn sierpinski(corners: [Vec2; 3], depth: usize) -> Vec<[Vec2; 3]> {
+    (0..depth).fold(vec![corners], |triangles, _| {
+        triangles
+            .into_iter()
+            .flat_map(|[a, b, c]| {
+                let (ab, bc, ca) = (a.midpoint(b), b.midpoint(c), c.midpoint(a));
+                [[a, ab, ca], [ab, b, bc], [ca, bc, c]]
+            })
+            .collect()
+    })
+}
+
+/// Shape of a recursive [`tree`].
+#[derive(Clone, Copy, Debug)]
+pub struct Branching {
+    /// Angle between each branch and its parent, in radians.
+    pub angle: f32,
+    /// Length of each branch relative to its parent.
+    pub length_scale: f32,
+    pub depth: usize,
+}
+
+impl Default for Branching {
+    fn default() -> Self {
+        Self {
+            angle: 25_f32.to_radians(),
+            length_scale: 0.67,
+            depth: 9,
+        }
+    }
+}
+
+/// Binary tree growing up from `root`, splitting into two branches turned
+/// either way by `branching.angle` at the end of every limb.
+pub fn tree(root: Vec2, length: f32, branching: Branching) -> Vec<Segment> {
+    let mut segments = Vec::with_capacity((1 << (branching.depth + 1)) - 1);
+    branch(root, FRAC_PI_2, length, 0, branching, &mut segments);
+    segments
+}
+
+fn branch(
+    start: Vec2,
+    heading: f32,
+    length: f32,
+    depth: usize,
+    branching: Branching,
+    segments: &mut Vec<Segment>,
+) {
+    let end = start + Vec2::from_angle(heading) * length;
+    segments.push(Segment { start, end, depth });
+
+    if depth < branching.depth {
+        let length = length * branching.length_scale;
+        for turn in [branching.angle, -branching.angle] {
+            branch(end, heading + turn, length, depth + 1, branching, segments);
+        }
+    }
+}
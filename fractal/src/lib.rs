@@ -0,0 +1,5 @@
+mod geometry;
+mod sketch;
+
+pub use geometry::*;
+pub use sketch::*;
@@ -0,0 +1,10 @@
+use bevy::prelude::*;
+use fractal::FractalPlugin;
+use heep::Sketch;
+
+fn main() {
+    App::new()
+        .add_plugins(Sketch::new("heep - Fractals").with_background(Color::BLACK))
+        .add_plugins(FractalPlugin)
+        .run();
+}
@@ -0,0 +1,143 @@
+use bevy::prelude::*;
+use heep::{Perlin, Segment, WorldBounds};
+
+use crate::{cantor_set, koch_snowflake, sierpinski, tree, Branching};
+
+/// Which fractal is drawn. Switched with the number keys.
+#[derive(Resource, Default, Clone, Copy, PartialEq, Eq)]
+pub enum Fractal {
+    #[default]
+    Tree,
+    Koch,
+    Cantor,
+    Sierpinski,
+}
+
+/// Parameters shared by the fractals, adjusted from the keyboard.
+#[derive(Resource)]
+pub struct FractalSettings {
+    pub depth: usize,
+    pub branching: Branching,
+    /// Sways the tree's branch angle with Perlin noise.
+    pub animate: bool,
+}
+
+impl Default for FractalSettings {
+    fn default() -> Self {
+        Self {
+            depth: 4,
+            branching: Branching::default(),
+            animate: false,
+        }
+    }
+}
+
+/// Draws the fractals in `geometry` with gizmos.
+///
+/// `1`-`4` pick the tree, Koch snowflake, Cantor set or Sierpinski triangle.
+/// Up and down change the depth, left and right the tree's branch angle, and
+/// `A` toggles animating the branch angle with noise.
+pub struct FractalPlugin;
+
+impl Plugin for FractalPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<Fractal>();
+        app.init_resource::<FractalSettings>();
+        app.add_systems(Update, (Self::controls, Self::animate, Self::draw).chain());
+    }
+}
+
+impl FractalPlugin {
+    const MAX_DEPTH: usize = 12;
+    const ANGLE_STEP: f32 = 0.05;
+    const NOISE_SPEED: f32 = 0.3;
+    const COLOR: Color = Color::srgb(0.95, 0.9, 0.75);
+
+    fn controls(
+        keyboard_input: Res<ButtonInput<KeyCode>>,
+        mut fractal: ResMut<Fractal>,
+        mut settings: ResMut<FractalSettings>,
+    ) {
+        for (key, choice) in [
+            (KeyCode::Digit1, Fractal::Tree),
+            (KeyCode::Digit2, Fractal::Koch),
+            (KeyCode::Digit3, Fractal::Cantor),
+            (KeyCode::Digit4, Fractal::Sierpinski),
+        ] {
+            if keyboard_input.just_pressed(key) {
+                *fractal = choice;
+            }
+        }
+
+        if keyboard_input.just_pressed(KeyCode::ArrowUp) {
+            settings.depth = (settings.depth + 1).min(Self::MAX_DEPTH);
+        }
+        if keyboard_input.just_pressed(KeyCode::ArrowDown) {
+            settings.depth = settings.depth.saturating_sub(1);
+        }
+        if keyboard_input.pressed(KeyCode::ArrowRight) {
+            settings.branching.angle += Self::ANGLE_STEP;
+        }
+        if keyboard_input.pressed(KeyCode::ArrowLeft) {
+            settings.branching.angle -= Self::ANGLE_STEP;
+        }
+        if keyboard_input.just_pressed(KeyCode::KeyA) {
+            settings.animate = !settings.animate;
+        }
+    }
+
+    fn animate(time: Res<Time>, perlin: Res<Perlin>, mut settings: ResMut<FractalSettings>) {
+        if settings.animate {
+            let sway = perlin.noise1(time.elapsed_seconds() * Self::NOISE_SPEED);
+            settings.branching.angle = (sway * 0.5 + 0.5) * std::f32::consts::FRAC_PI_2;
+        }
+    }
+
+    fn draw(
+        fractal: Res<Fractal>,
+        settings: Res<FractalSettings>,
+        bounds: Res<WorldBounds>,
+        mut gizmos: Gizmos,
+    ) {
+        let bounds = bounds.0;
+        if bounds.is_empty() {
+            return;
+        }
+        let size = bounds.height() * 0.8;
+        let mut line = |segment: &Segment| gizmos.line_2d(segment.start, segment.end, Self::COLOR);
+
+        match *fractal {
+            Fractal::Tree => {
+                let branching = Branching {
+                    depth: settings.depth.max(1) + 4,
+                    ..settings.branching
+                };
+                let root = Vec2::new(bounds.center().x, bounds.min.y + bounds.height() * 0.05);
+                tree(root, size * 0.3, branching).iter().for_each(&mut line);
+            }
+            Fractal::Koch => {
+                koch_snowflake(bounds.center(), size * 0.55, settings.depth.min(7))
+                    .iter()
+                    .for_each(&mut line);
+            }
+            Fractal::Cantor => {
+                let start = bounds.center() + Vec2::new(-size * 0.8, size * 0.4);
+                cantor_set(start, size * 1.6, size / 8., settings.depth.min(8))
+                    .iter()
+                    .for_each(&mut line);
+            }
+            Fractal::Sierpinski => {
+                let corners = [0., 1., 2.].map(|i| {
+                    bounds.center()
+                        + Vec2::from_angle(
+                            std::f32::consts::FRAC_PI_2 + i * std::f32::consts::TAU / 3.,
+                        ) * size
+                            * 0.6
+                });
+                for [a, b, c] in sierpinski(corners, settings.depth.min(8)) {
+                    gizmos.linestrip_2d([a, b, c, a], Self::COLOR);
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,108 @@
+use bevy::prelude::*;
+use fractal::{cantor_set, koch_curve, koch_snowflake, sierpinski, tree, Branching};
+use heep::Segment;
+
+fn length(segments: &[Segment]) -> f32 {
+    segments.iter().map(|s| s.start.distance(s.end)).sum()
+}
+
+fn area([a, b, c]: [Vec2; 3]) -> f32 {
+    (b - a).perp_dot(c - a).abs() / 2.
+}
+
+#[test]
+fn koch_curve_quadruples_segments_and_grows_by_a_third() {
+    for depth in 0..6 {
+        let curve = koch_curve(Vec2::ZERO, Vec2::new(9., 0.), depth);
+
+        assert_eq!(curve.len(), 4usize.pow(depth as u32));
+        assert!((length(&curve) - 9. * (4f32 / 3.).powi(depth as i32)).abs() < 1e-3);
+        assert_eq!(curve.first().unwrap().start, Vec2::ZERO);
+        assert!((curve.last().unwrap().end - Vec2::new(9., 0.)).length() < 1e-4);
+    }
+}
+
+#[test]
+fn koch_snowflake_bumps_outwards() {
+    let radius = 10.;
+    let snowflake = koch_snowflake(Vec2::ZERO, radius, 1);
+
+    assert_eq!(snowflake.len(), 12);
+    // The bump tips lie on the circle through the corners.
+    let farthest = snowflake
+        .iter()
+        .map(|segment| segment.end.length())
+        .fold(0., f32::max);
+    assert!((farthest - radius).abs() < 1e-4);
+    assert!(snowflake
+        .iter()
+        .all(|segment| segment.end.length() > radius / 2. - 1e-4));
+}
+
+#[test]
+fn cantor_set_keeps_two_thirds_per_level() {
+    let depth = 5;
+    let set = cantor_set(Vec2::new(0., 0.), 81., 2., depth);
+
+    assert_eq!(set.len(), (1 << (depth + 1)) - 1);
+    for level in 0..=depth {
+        let row: Vec<_> = set.iter().filter(|s| s.depth == level).copied().collect();
+        assert_eq!(row.len(), 1 << level);
+        assert!((length(&row) - 81. * (2f32 / 3.).powi(level as i32)).abs() < 1e-3);
+        assert!(row.iter().all(|s| s.start.y == -2. * level as f32));
+    }
+}
+
+#[test]
+fn sierpinski_keeps_three_quarters_of_the_area() {
+    let corners = [Vec2::ZERO, Vec2::new(8., 0.), Vec2::new(4., 8.)];
+
+    for depth in 0..6 {
+        let triangles = sierpinski(corners, depth);
+        let total: f32 = triangles.iter().copied().map(area).sum();
+
+        assert_eq!(triangles.len(), 3usize.pow(depth as u32));
+        assert!((total - area(corners) * 0.75f32.powi(depth as i32)).abs() < 1e-3);
+    }
+}
+
+#[test]
+fn tree_has_a_branch_per_node_of_a_binary_tree() {
+    let branching = Branching {
+        angle: 0.4,
+        length_scale: 0.5,
+        depth: 6,
+    };
+    let branches = tree(Vec2::ZERO, 10., branching);
+
+    assert_eq!(branches.len(), (1 << 7) - 1);
+    assert_eq!(branches.iter().filter(|b| b.depth == 6).count(), 64);
+    assert!((branches[0].end - Vec2::new(0., 10.)).length() < 1e-5);
+}
+
+#[test]
+fn tree_branches_turn_by_the_branch_angle() {
+    let branching = Branching {
+        angle: 0.3,
+        length_scale: 0.5,
+        depth: 1,
+    };
+    let [trunk, left, right] = tree(Vec2::ZERO, 4., branching)[..] else {
+        panic!("expected a trunk and two branches");
+    };
+
+    assert_eq!(left.start, trunk.end);
+    assert!(((left.end - left.start).length() - 2.).abs() < 1e-5);
+    assert!(((left.end - left.start).angle_between(trunk.end - trunk.start) + 0.3).abs() < 1e-5);
+    assert!(((right.end - right.start).angle_between(trunk.end - trunk.start) - 0.3).abs() < 1e-5);
+
+    let straight = tree(
+        Vec2::ZERO,
+        4.,
+        Branching {
+            angle: 0.,
+            ..branching
+        },
+    );
+    assert!((straight[2].end - Vec2::new(0., 6.)).length() < 1e-5);
+}
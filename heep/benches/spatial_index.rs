@@ -0,0 +1,85 @@
+//! Compares `SpatialIndex` queries against brute force. Run with
+//! `cargo bench -p heep`.
+
+use std::{hint::black_box, time::Instant};
+
+use bevy::prelude::*;
+use bevy_turborand::{rng::Rng, SeededCore, TurboRand};
+use heep::SpatialIndex;
+
+const RADIUS: f32 = 6.;
+const K: usize = 8;
+
+fn time(name: &str, count: usize, f: impl FnOnce() -> usize) {
+    let start = Instant::now();
+    let found = black_box(f());
+    println!(
+        "{name:>24} n={count:<6} {:>10.2?} ({found} found)",
+        start.elapsed()
+    );
+}
+
+fn main() {
+    let rng = Rng::with_seed(3);
+
+    for count in [1_000, 5_000, 20_000] {
+        // Keep the density roughly what a flock sketch sees.
+        let half_size = (count as f32).sqrt() * 2.;
+        let points: Vec<Vec2> = (0..count)
+            .map(|_| Vec2::new(rng.f32_normalized(), rng.f32_normalized()) * half_size)
+            .collect();
+
+        time("index build", count, || {
+            let mut index = SpatialIndex::new(RADIUS);
+            for (i, &point) in points.iter().enumerate() {
+                index.insert(Entity::from_raw(i as u32), point, Vec2::ZERO);
+            }
+            index.len()
+        });
+
+        let mut index = SpatialIndex::new(RADIUS);
+        for (i, &point) in points.iter().enumerate() {
+            index.insert(Entity::from_raw(i as u32), point, Vec2::ZERO);
+        }
+
+        time("index radius", count, || {
+            points
+                .iter()
+                .map(|&point| index.query_radius(point, RADIUS).len())
+                .sum()
+        });
+        time("brute force radius", count, || {
+            points
+                .iter()
+                .map(|&point| {
+                    points
+                        .iter()
+                        .filter(|other| other.distance_squared(point) <= RADIUS * RADIUS)
+                        .count()
+                })
+                .sum()
+        });
+
+        let queries = &points[..count / 10];
+        time("index nearest", queries.len(), || {
+            queries
+                .iter()
+                .map(|&point| index.nearest(point, K).len())
+                .sum()
+        });
+        time("brute force nearest", queries.len(), || {
+            queries
+                .iter()
+                .map(|&point| {
+                    let mut sorted = points.clone();
+                    sorted.sort_by(|a, b| {
+                        a.distance_squared(point)
+                            .total_cmp(&b.distance_squared(point))
+                    });
+                    sorted.truncate(K);
+                    sorted.len()
+                })
+                .sum()
+        });
+    }
+}
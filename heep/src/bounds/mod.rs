@@ -0,0 +1,139 @@
+use bevy::prelude::*;
+
+use crate::{PhysicsSet, Position, PreviousPosition, Shape, Velocity};
+
+/// Visible extents of the world, kept in sync with the 2d camera's projection.
+#[derive(Resource, Default, Clone, Copy)]
+pub struct WorldBounds(pub Rect);
+
+/// What happens to an entity once it reaches the edge of [`WorldBounds`].
+/// An entity's `Shape` is treated as its half extents.
+#[derive(Component, Clone, Copy)]
+pub enum EdgeBehavior {
+    /// Reflects off the edge, keeping `restitution` of the outgoing speed.
+    Bounce { restitution: f32 },
+    /// Reappears on the opposite edge once fully outside.
+    Wrap,
+    /// Stops at the edge.
+    Clamp,
+    /// Despawns once fully outside.
+    Despawn,
+}
+
+pub struct BoundsPlugin;
+
+impl Plugin for BoundsPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<WorldBounds>();
+        app.add_systems(PreUpdate, Self::sync_bounds);
+        app.add_systems(FixedUpdate, Self::enforce_edges.after(PhysicsSet));
+    }
+}
+
+impl BoundsPlugin {
+    fn sync_bounds(
+        mut bounds: ResMut<WorldBounds>,
+        camera: Query<(&OrthographicProjection, &GlobalTransform), With<Camera2d>>,
+    ) {
+        if let Ok((projection, transform)) = camera.get_single() {
+            let center = transform.translation().truncate();
+            let area =
+                Rect::from_corners(projection.area.min + center, projection.area.max + center);
+            if bounds.0 != area {
+                bounds.0 = area;
+            }
+        }
+    }
+
+    #[allow(clippy::type_complexity)]
+    fn enforce_edges(
+        mut commands: Commands,
+        bounds: Res<WorldBounds>,
+        mut bodies: Query<(
+            Entity,
+            &EdgeBehavior,
+            &mut Position,
+            Option<&mut Velocity>,
+            Option<&mut PreviousPosition>,
+            Option<&Shape>,
+        )>,
+    ) {
+        if bounds.0.is_empty() {
+            return;
+        }
+
+        for (entity, edge, mut position, velocity, previous, shape) in &mut bodies {
+            let extents = shape.map_or(Vec2::ZERO, |shape| shape.0);
+            let min = bounds.0.min + extents;
+            let max = bounds.0.max - extents;
+
+            match *edge {
+                EdgeBehavior::Bounce { restitution } => {
+                    let mut velocity = velocity;
+                    for axis in 0..2 {
+                        let outward = if position.0[axis] < min[axis] {
+                            position.0[axis] = min[axis];
+                            -1.
+                        } else if position.0[axis] > max[axis] {
+                            position.0[axis] = max[axis];
+                            1.
+                        } else {
+                            continue;
+                        };
+
+                        if let Some(velocity) = velocity.as_mut() {
+                            if velocity.0[axis] * outward > 0. {
+                                velocity.0[axis] *= -restitution;
+                            }
+                        }
+                    }
+                }
+                EdgeBehavior::Wrap => {
+                    let outer_min = bounds.0.min - extents;
+                    let outer_max = bounds.0.max + extents;
+                    let mut offset = Vec2::ZERO;
+                    for axis in 0..2 {
+                        let span = outer_max[axis] - outer_min[axis];
+                        if position.0[axis] < outer_min[axis] {
+                            offset[axis] = span;
+                        } else if position.0[axis] > outer_max[axis] {
+                            offset[axis] = -span;
+                        }
+                    }
+
+                    if offset != Vec2::ZERO {
+                        position.0 += offset;
+                        // Move the interpolation origin too so the entity
+                        // doesn't streak across the screen.
+                        if let Some(mut previous) = previous {
+                            previous.0 += offset;
+                        }
+                    }
+                }
+                EdgeBehavior::Clamp => {
+                    let clamped = position.0.clamp(min, max);
+                    if clamped != position.0 {
+                        if let Some(mut velocity) = velocity {
+                            if clamped.x != position.0.x {
+                                velocity.0.x = 0.;
+                            }
+                            if clamped.y != position.0.y {
+                                velocity.0.y = 0.;
+                            }
+                        }
+                        position.0 = clamped;
+                    }
+                }
+                EdgeBehavior::Despawn => {
+                    let outside = position.0.x + extents.x < bounds.0.min.x
+                        || position.0.x - extents.x > bounds.0.max.x
+                        || position.0.y + extents.y < bounds.0.min.y
+                        || position.0.y - extents.y > bounds.0.max.y;
+                    if outside {
+                        commands.entity(entity).despawn_recursive();
+                    }
+                }
+            }
+        }
+    }
+}
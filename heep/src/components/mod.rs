@@ -1,10 +1,130 @@
 use bevy::prelude::*;
 
-#[derive(Component, Default)]
+#[derive(Component, Default, Clone, Copy)]
 pub struct Position(pub Vec2);
 
-#[derive(Component, Default)]
+/// Position at the start of the current fixed step, used to interpolate
+/// rendering between steps. Code that moves an entity in one jump must set
+/// it too, see [`Placement`](crate::Placement).
+#[derive(Component, Default, Clone, Copy)]
+pub struct PreviousPosition(pub Vec2);
+
+#[derive(Component, Default, Clone, Copy)]
 pub struct Velocity(pub Vec2);
 
-#[derive(Component, Default)]
+#[derive(Component, Default, Clone, Copy)]
+pub struct Acceleration(pub Vec2);
+
+/// Acceleration of the last fixed step, which [`Integrator::Verlet`]
+/// averages with the current one. `None` until the body has taken a step.
+///
+/// [`Integrator::Verlet`]: crate::Integrator::Verlet
+#[derive(Component, Default, Clone, Copy)]
+pub struct PreviousAcceleration(pub Option<Vec2>);
+
+#[derive(Component, Default, Clone, Copy)]
 pub struct Shape(pub Vec2);
+
+/// Counter-clockwise rotation in radians.
+#[derive(Component, Default, Clone, Copy)]
+pub struct Rotation(pub f32);
+
+/// Rotation at the start of the current fixed step, used to interpolate
+/// rendering between steps.
+#[derive(Component, Default, Clone, Copy)]
+pub struct PreviousRotation(pub f32);
+
+/// Radians per second.
+#[derive(Component, Default, Clone, Copy)]
+pub struct AngularVelocity(pub f32);
+
+#[derive(Component, Default, Clone, Copy)]
+pub struct AngularAcceleration(pub f32);
+
+/// Angular counterpart of [`PreviousAcceleration`].
+#[derive(Component, Default, Clone, Copy)]
+pub struct PreviousAngularAcceleration(pub Option<f32>);
+
+/// Overrides the moment of inertia otherwise derived from `Mass` and `Shape`.
+#[derive(Component, Clone, Copy)]
+pub struct MomentOfInertia(pub f32);
+
+impl MomentOfInertia {
+    /// Moment of inertia of a solid box with the given half extents.
+    pub fn from_shape(mass: f32, shape: Vec2) -> Self {
+        Self(mass * shape.length_squared() / 3.)
+    }
+}
+
+/// Rotates the body to point along its velocity instead of integrating
+/// its angular motion.
+#[derive(Component, Default, Clone, Copy)]
+pub struct FaceVelocity;
+
+/// Z-order used when projecting `Position` onto `Transform`. Higher layers
+/// draw on top.
+#[derive(Component, Default, Clone, Copy)]
+pub struct Layer(pub f32);
+
+#[derive(Component, Clone, Copy)]
+pub struct Mass(pub f32);
+
+impl Default for Mass {
+    fn default() -> Self {
+        Self(1.)
+    }
+}
+
+/// Net force applied to a body during the current step. Cleared by the
+/// integrator once it has been turned into acceleration.
+#[derive(Component, Default)]
+pub struct Forces(pub Vec2);
+
+impl Forces {
+    pub fn apply_force(&mut self, force: Vec2) {
+        self.0 += force;
+    }
+
+    pub fn clear(&mut self) {
+        self.0 = Vec2::ZERO;
+    }
+}
+
+/// Net torque applied to a body during the current step. Cleared by the
+/// integrator once it has been turned into angular acceleration.
+#[derive(Component, Default)]
+pub struct Torques(pub f32);
+
+impl Torques {
+    pub fn apply_torque(&mut self, torque: f32) {
+        self.0 += torque;
+    }
+
+    /// Applies the torque of `force` acting at `offset` from the center of mass.
+    pub fn apply_force_at(&mut self, force: Vec2, offset: Vec2) {
+        self.0 += offset.perp_dot(force);
+    }
+
+    pub fn clear(&mut self) {
+        self.0 = 0.;
+    }
+}
+
+#[derive(Bundle, Default)]
+pub struct BodyBundle {
+    pub position: Position,
+    pub velocity: Velocity,
+    pub acceleration: Acceleration,
+    pub previous_acceleration: PreviousAcceleration,
+    pub mass: Mass,
+    pub forces: Forces,
+}
+
+#[derive(Bundle, Default)]
+pub struct AngularBundle {
+    pub rotation: Rotation,
+    pub angular_velocity: AngularVelocity,
+    pub angular_acceleration: AngularAcceleration,
+    pub previous_angular_acceleration: PreviousAngularAcceleration,
+    pub torques: Torques,
+}
@@ -0,0 +1,190 @@
+use bevy::prelude::*;
+
+use crate::{
+    EdgeBehavior, Forces, PhysicsSet, Position, Shape, SpatialIndex, SpatialIndexPlugin, Vehicle,
+    Velocity, WorldBounds,
+};
+
+/// Shared settings for a group of boids. Lives on its own entity, which each
+/// member's [`Boid`] points at.
+#[derive(Component, Clone, Copy)]
+pub struct Flock {
+    pub separation_weight: f32,
+    pub alignment_weight: f32,
+    pub cohesion_weight: f32,
+    /// Neighbors closer than this are steered away from.
+    pub separation_radius: f32,
+    /// Neighbors further than this are ignored.
+    pub perception_radius: f32,
+    /// Half-angle of the vision cone in radians. `PI` sees all around.
+    pub field_of_view: f32,
+}
+
+impl Default for Flock {
+    fn default() -> Self {
+        Self {
+            separation_weight: 1.5,
+            alignment_weight: 1.,
+            cohesion_weight: 1.,
+            separation_radius: 3.,
+            perception_radius: 6.,
+            field_of_view: 2.,
+        }
+    }
+}
+
+/// Member of the [`Flock`] on the given entity. Needs a `Vehicle` to steer.
+#[derive(Component, Clone, Copy)]
+pub struct Boid {
+    pub flock: Entity,
+}
+
+impl Flock {
+    fn sees(&self, position: Vec2, heading: Vec2, other: Vec2) -> bool {
+        let offset = other - position;
+        let distance_squared = offset.length_squared();
+        if distance_squared > self.perception_radius * self.perception_radius
+            || distance_squared == 0.
+        {
+            return false;
+        }
+
+        heading == Vec2::ZERO
+            || heading.dot(offset / distance_squared.sqrt()) >= self.field_of_view.cos()
+    }
+
+    /// Combined separation, alignment and cohesion force on a boid from the
+    /// `neighbors` it can see, as `(position, velocity)`.
+    pub fn steer(
+        &self,
+        vehicle: &Vehicle,
+        position: Vec2,
+        velocity: Vec2,
+        neighbors: impl IntoIterator<Item = (Vec2, Vec2)>,
+    ) -> Vec2 {
+        let heading = velocity.normalize_or_zero();
+        let mut separation = Vec2::ZERO;
+        let mut alignment = Vec2::ZERO;
+        let mut cohesion = Vec2::ZERO;
+        let mut crowded = 0;
+        let mut seen = 0;
+
+        for (other, other_velocity) in neighbors {
+            if !self.sees(position, heading, other) {
+                continue;
+            }
+
+            let offset = position - other;
+            let distance = offset.length();
+            if distance < self.separation_radius {
+                separation += offset / (distance * distance);
+                crowded += 1;
+            }
+
+            alignment += other_velocity;
+            cohesion += other;
+            seen += 1;
+        }
+
+        let mut force = Vec2::ZERO;
+        if crowded > 0 {
+            let desired = separation.normalize_or_zero() * vehicle.max_speed;
+            force += vehicle.steer(velocity, desired) * self.separation_weight;
+        }
+        if seen > 0 {
+            let desired = alignment.normalize_or_zero() * vehicle.max_speed;
+            force += vehicle.steer(velocity, desired) * self.alignment_weight;
+
+            let center = cohesion / seen as f32;
+            force += vehicle.seek(position, velocity, center) * self.cohesion_weight;
+        }
+
+        force.clamp_length_max(vehicle.max_force)
+    }
+}
+
+pub struct FlockingPlugin;
+
+impl Plugin for FlockingPlugin {
+    fn build(&self, app: &mut App) {
+        if !app.is_plugin_added::<SpatialIndexPlugin>() {
+            app.add_plugins(SpatialIndexPlugin);
+        }
+        app.add_systems(FixedUpdate, Self::flock.before(PhysicsSet));
+    }
+}
+
+impl FlockingPlugin {
+    /// Shifts by whole periods of the `wrap` area that bring a copy of it
+    /// within `radius` of `center`. Always includes `Vec2::ZERO`.
+    fn wrap_offsets(wrap: Rect, center: Vec2, radius: f32) -> Vec<Vec2> {
+        let size = wrap.size();
+        let mut offsets = Vec::new();
+        for x in -1..=1 {
+            for y in -1..=1 {
+                let offset = Vec2::new(x as f32, y as f32) * size;
+                let image = Rect::from_corners(wrap.min + offset, wrap.max + offset);
+                let closest = center.clamp(image.min, image.max);
+                if offset == Vec2::ZERO || closest.distance_squared(center) < radius * radius {
+                    offsets.push(offset);
+                }
+            }
+        }
+        offsets
+    }
+
+    /// Neighbors are looked up in the [`SpatialIndex`] so each boid only
+    /// checks those near it. Boids that wrap around the [`WorldBounds`] also
+    /// see neighbors across the opposite edges.
+    #[allow(clippy::type_complexity)]
+    fn flock(
+        index: Res<SpatialIndex>,
+        bounds: Option<Res<WorldBounds>>,
+        flocks: Query<&Flock>,
+        members: Query<(&Boid, &Velocity)>,
+        mut boids: Query<(
+            &Boid,
+            &Vehicle,
+            &Position,
+            &Velocity,
+            &mut Forces,
+            Option<&EdgeBehavior>,
+            Option<&Shape>,
+        )>,
+    ) {
+        let bounds = bounds.map_or(Rect::default(), |bounds| bounds.0);
+        boids.par_iter_mut().for_each(
+            |(boid, vehicle, position, velocity, mut forces, edge, shape)| {
+                let Ok(flock) = flocks.get(boid.flock) else {
+                    return;
+                };
+
+                let radius = flock.perception_radius;
+                let offsets = match edge {
+                    Some(EdgeBehavior::Wrap) if !bounds.is_empty() => {
+                        // Matches the area `BoundsPlugin` wraps this boid over.
+                        let extents = shape.map_or(Vec2::ZERO, |shape| shape.0);
+                        let wrap = Rect::from_corners(bounds.min - extents, bounds.max + extents);
+                        Self::wrap_offsets(wrap, position.0, radius)
+                    }
+                    _ => vec![Vec2::ZERO],
+                };
+
+                let mut neighbors = Vec::new();
+                for offset in offsets {
+                    // Neighbors near `position - offset` appear shifted by
+                    // `offset` from this boid's side of the seam.
+                    index.for_each_in_radius(position.0 - offset, radius, |entry| {
+                        if let Ok((other, other_velocity)) = members.get(entry.entity) {
+                            if other.flock == boid.flock {
+                                neighbors.push((entry.position + offset, other_velocity.0));
+                            }
+                        }
+                    });
+                }
+
+                forces.apply_force(flock.steer(vehicle, position.0, velocity.0, neighbors));
+            },
+        );
+    }
+}
@@ -0,0 +1,166 @@
+use std::f32::consts::TAU;
+
+use bevy::prelude::*;
+
+use crate::{Forces, Perlin, PhysicsSet, Position, Vehicle, Velocity};
+
+/// Regenerates a [`FlowField`] from noise every step, moving through the
+/// noise's third dimension over time.
+#[derive(Clone, Copy)]
+pub struct NoiseFlow {
+    /// Noise coordinates per world unit.
+    pub scale: f32,
+    /// Noise coordinates per second along the time axis. Zero keeps the field still.
+    pub speed: f32,
+}
+
+/// Grid of unit direction vectors covering `bounds`. Points outside the
+/// grid read the nearest cell.
+#[derive(Resource, Clone)]
+pub struct FlowField {
+    bounds: Rect,
+    cell_size: f32,
+    columns: usize,
+    rows: usize,
+    vectors: Vec<Vec2>,
+    pub noise: Option<NoiseFlow>,
+    /// Draws the field as arrows.
+    pub debug: bool,
+}
+
+impl FlowField {
+    pub fn new(bounds: Rect, cell_size: f32) -> Self {
+        let columns = (bounds.width() / cell_size).ceil().max(1.) as usize;
+        let rows = (bounds.height() / cell_size).ceil().max(1.) as usize;
+
+        Self {
+            bounds,
+            cell_size,
+            columns,
+            rows,
+            vectors: vec![Vec2::ZERO; columns * rows],
+            noise: None,
+            debug: false,
+        }
+    }
+
+    pub fn with_noise(mut self, scale: f32, speed: f32) -> Self {
+        self.noise = Some(NoiseFlow { scale, speed });
+        self
+    }
+
+    pub fn with_debug(mut self, debug: bool) -> Self {
+        self.debug = debug;
+        self
+    }
+
+    pub fn cell_size(&self) -> f32 {
+        self.cell_size
+    }
+
+    pub fn columns(&self) -> usize {
+        self.columns
+    }
+
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+
+    pub fn cell_center(&self, column: usize, row: usize) -> Vec2 {
+        self.bounds.min + (Vec2::new(column as f32, row as f32) + 0.5) * self.cell_size
+    }
+
+    /// Sets every cell from the direction `f` returns for its center.
+    pub fn fill_with(&mut self, f: impl Fn(Vec2) -> Vec2) {
+        for row in 0..self.rows {
+            for column in 0..self.columns {
+                let center = self.cell_center(column, row);
+                self.vectors[row * self.columns + column] = f(center).normalize_or_zero();
+            }
+        }
+    }
+
+    pub fn fill_uniform(&mut self, direction: Vec2) {
+        self.fill_with(|_| direction);
+    }
+
+    /// Points each cell at an angle taken from Perlin noise at depth `z`.
+    pub fn fill_noise(&mut self, perlin: &Perlin, scale: f32, z: f32) {
+        self.fill_with(|point| Vec2::from_angle(perlin.noise3((point * scale).extend(z)) * TAU));
+    }
+
+    pub fn lookup(&self, point: Vec2) -> Vec2 {
+        let cell = ((point - self.bounds.min) / self.cell_size).floor();
+        let column = (cell.x.max(0.) as usize).min(self.columns - 1);
+        let row = (cell.y.max(0.) as usize).min(self.rows - 1);
+        self.vectors[row * self.columns + column]
+    }
+}
+
+/// Steers a `Vehicle` to travel along the [`FlowField`] at its position.
+#[derive(Component, Clone, Copy)]
+pub struct FollowField {
+    pub weight: f32,
+}
+
+impl Default for FollowField {
+    fn default() -> Self {
+        Self { weight: 1. }
+    }
+}
+
+pub struct FlowFieldPlugin;
+
+impl Plugin for FlowFieldPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            FixedUpdate,
+            (Self::animate, Self::follow)
+                .chain()
+                .before(PhysicsSet)
+                .run_if(resource_exists::<FlowField>),
+        );
+        app.add_systems(Update, Self::draw.run_if(resource_exists::<FlowField>));
+    }
+}
+
+impl FlowFieldPlugin {
+    const DEBUG_COLOR: Color = Color::srgba(1., 1., 1., 0.3);
+
+    fn animate(time: Res<Time>, perlin: Option<Res<Perlin>>, mut field: ResMut<FlowField>) {
+        let (Some(noise), Some(perlin)) = (field.noise, perlin) else {
+            return;
+        };
+
+        // Still fields only need refilling when something else changed them.
+        if noise.speed != 0. || field.is_changed() {
+            let z = time.elapsed_seconds() * noise.speed;
+            field.fill_noise(&perlin, noise.scale, z);
+        }
+    }
+
+    fn follow(
+        field: Res<FlowField>,
+        mut agents: Query<(&FollowField, &Vehicle, &Position, &Velocity, &mut Forces)>,
+    ) {
+        for (follow, vehicle, position, velocity, mut forces) in &mut agents {
+            let desired = field.lookup(position.0) * vehicle.max_speed;
+            forces.apply_force(vehicle.steer(velocity.0, desired) * follow.weight);
+        }
+    }
+
+    fn draw(field: Res<FlowField>, mut gizmos: Gizmos) {
+        if !field.debug {
+            return;
+        }
+
+        let length = field.cell_size * 0.4;
+        for row in 0..field.rows {
+            for column in 0..field.columns {
+                let center = field.cell_center(column, row);
+                let direction = field.vectors[row * field.columns + column] * length;
+                gizmos.arrow_2d(center - direction, center + direction, Self::DEBUG_COLOR);
+            }
+        }
+    }
+}
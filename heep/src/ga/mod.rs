@@ -0,0 +1,204 @@
+use bevy_turborand::TurboRand;
+
+/// Genetic material evolved by a [`Population`].
+pub trait Genome: Clone {
+    /// Whatever is needed to build a random genome, such as its length.
+    type Config;
+
+    fn random(config: &Self::Config, rng: &impl TurboRand) -> Self;
+
+    /// Combines two parents into a child.
+    fn crossover(&self, other: &Self, rng: &impl TurboRand) -> Self;
+
+    /// Randomly changes each gene with probability `rate`.
+    fn mutate(&mut self, rate: f32, rng: &impl TurboRand);
+}
+
+/// How parents are picked from a [`Population`].
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum Selection {
+    /// Chance proportional to fitness. Negative fitness counts as zero.
+    #[default]
+    Roulette,
+    /// Fittest of `size` members picked uniformly at random.
+    Tournament { size: usize },
+    /// Chance proportional to rank, so the fittest member is only `n` times
+    /// as likely as the least fit however far ahead it is.
+    Rank,
+}
+
+/// Fitness summary of one generation.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Statistics {
+    pub generation: usize,
+    pub best: f32,
+    pub mean: f32,
+    pub worst: f32,
+}
+
+/// A generation of genomes and their fitness.
+#[derive(Clone, Debug)]
+pub struct Population<G: Genome> {
+    pub members: Vec<G>,
+    /// Fitness of each member, filled by [`Population::evaluate`] or
+    /// [`Population::set_fitness`].
+    pub fitness: Vec<f32>,
+    pub generation: usize,
+    pub selection: Selection,
+    /// How many of the fittest members are copied unchanged into the next
+    /// generation.
+    pub elitism: usize,
+    pub mutation_rate: f32,
+    /// Statistics of every evaluated generation, oldest first.
+    pub history: Vec<Statistics>,
+}
+
+impl<G: Genome> Population<G> {
+    pub fn new(members: Vec<G>) -> Self {
+        Self {
+            fitness: vec![0.; members.len()],
+            members,
+            generation: 0,
+            selection: Selection::default(),
+            elitism: 0,
+            mutation_rate: 0.01,
+            history: Vec::new(),
+        }
+    }
+
+    pub fn random(size: usize, config: &G::Config, rng: &impl TurboRand) -> Self {
+        Self::new((0..size).map(|_| G::random(config, rng)).collect())
+    }
+
+    pub fn with_selection(mut self, selection: Selection) -> Self {
+        self.selection = selection;
+        self
+    }
+
+    pub fn with_elitism(mut self, elitism: usize) -> Self {
+        self.elitism = elitism;
+        self
+    }
+
+    pub fn with_mutation_rate(mut self, mutation_rate: f32) -> Self {
+        self.mutation_rate = mutation_rate;
+        self
+    }
+
+    /// Scores every member and records the generation's statistics.
+    pub fn evaluate(&mut self, fitness: impl Fn(&G) -> f32) -> Statistics {
+        let fitness = self.members.iter().map(fitness).collect();
+        self.set_fitness(fitness)
+    }
+
+    /// Records fitness measured elsewhere, e.g. by running a simulation, in
+    /// the same order as `members`.
+    pub fn set_fitness(&mut self, fitness: Vec<f32>) -> Statistics {
+        assert_eq!(fitness.len(), self.members.len());
+        self.fitness = fitness;
+
+        let statistics = self.statistics();
+        self.history.push(statistics);
+        statistics
+    }
+
+    pub fn statistics(&self) -> Statistics {
+        let count = self.fitness.len().max(1) as f32;
+        Statistics {
+            generation: self.generation,
+            best: self
+                .fitness
+                .iter()
+                .copied()
+                .fold(f32::NEG_INFINITY, f32::max),
+            mean: self.fitness.iter().sum::<f32>() / count,
+            worst: self.fitness.iter().copied().fold(f32::INFINITY, f32::min),
+        }
+    }
+
+    pub fn best(&self) -> Option<(&G, f32)> {
+        self.members
+            .iter()
+            .zip(self.fitness.iter().copied())
+            .max_by(|(_, a), (_, b)| a.total_cmp(b))
+    }
+
+    /// Indices of the members, fittest first.
+    pub fn ranked(&self) -> Vec<usize> {
+        let mut order: Vec<_> = (0..self.members.len()).collect();
+        order.sort_by(|&a, &b| self.fitness[b].total_cmp(&self.fitness[a]));
+        order
+    }
+
+    /// Index of a parent picked by the population's [`Selection`], or `None`
+    /// if the population is empty.
+    pub fn select(&self, rng: &impl TurboRand) -> Option<usize> {
+        let count = self.members.len();
+        if count == 0 {
+            return None;
+        }
+
+        let index = match self.selection {
+            Selection::Roulette => {
+                let weights: Vec<_> = self.fitness.iter().map(|f| f.max(0.)).collect();
+                Self::weighted(&weights, rng).unwrap_or_else(|| rng.usize(..count))
+            }
+            Selection::Tournament { size } => (0..size.max(1))
+                .map(|_| rng.usize(..count))
+                .max_by(|&a, &b| self.fitness[a].total_cmp(&self.fitness[b]))
+                .unwrap(),
+            Selection::Rank => {
+                let mut weights = vec![0.; count];
+                for (rank, index) in self.ranked().into_iter().enumerate() {
+                    weights[index] = (count - rank) as f32;
+                }
+                Self::weighted(&weights, rng).unwrap()
+            }
+        };
+        Some(index)
+    }
+
+    fn weighted(weights: &[f32], rng: &impl TurboRand) -> Option<usize> {
+        let total: f32 = weights.iter().sum();
+        if total <= 0. {
+            return None;
+        }
+
+        let mut pick = rng.f32() * total;
+        for (index, weight) in weights.iter().enumerate() {
+            if pick < *weight {
+                return Some(index);
+            }
+            pick -= weight;
+        }
+        weights.iter().rposition(|&weight| weight > 0.)
+    }
+
+    /// Replaces the members with the next generation: the elite carried over
+    /// unchanged, then mutated children of selected parents. Fitness is reset
+    /// until the new generation is evaluated.
+    pub fn evolve(&mut self, rng: &impl TurboRand) {
+        let count = self.members.len();
+        let mut next: Vec<G> = self
+            .ranked()
+            .into_iter()
+            .take(self.elitism.min(count))
+            .map(|index| self.members[index].clone())
+            .collect();
+
+        while next.len() < count {
+            // Never `None` here, the population has members.
+            let (Some(a), Some(b)) = (self.select(rng), self.select(rng)) else {
+                break;
+            };
+            let (a, b) = (&self.members[a], &self.members[b]);
+            let mut child = a.crossover(b, rng);
+            child.mutate(self.mutation_rate, rng);
+            next.push(child);
+        }
+
+        self.members = next;
+        self.fitness = vec![0.; count];
+        self.generation += 1;
+    }
+}
@@ -0,0 +1,278 @@
+use bevy::prelude::*;
+
+use crate::{Forces, Mass, PhysicsSet, Position};
+
+/// Marks a massive body that pulls on every other body with `Mass` and `Forces`.
+#[derive(Component, Default, Clone, Copy)]
+pub struct Attractor;
+
+/// How pairwise attraction is summed each step.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum GravitySolver {
+    /// Sums every pair directly, O(n²).
+    Exact,
+    /// Approximates distant clusters by their center of mass, O(n log n).
+    /// Larger `theta` trades accuracy for speed; `0.` is exact.
+    BarnesHut { theta: f32 },
+}
+
+#[derive(Resource, Clone, Copy)]
+pub struct Gravitation {
+    /// Gravitational constant.
+    pub g: f32,
+    /// Distances are clamped to at least this to avoid the singularity at r = 0.
+    pub min_distance: f32,
+    pub solver: GravitySolver,
+}
+
+impl Default for Gravitation {
+    fn default() -> Self {
+        Self {
+            g: 1.,
+            min_distance: 5.,
+            solver: GravitySolver::Exact,
+        }
+    }
+}
+
+impl Gravitation {
+    /// Force pulling the body at `position` toward `other`.
+    pub fn attraction(&self, position: Vec2, mass: f32, other: Vec2, other_mass: f32) -> Vec2 {
+        let offset = other - position;
+        let distance = offset.length().max(self.min_distance);
+        offset.normalize_or_zero() * self.g * mass * other_mass / (distance * distance)
+    }
+
+    /// Net force on each of `bodies`, given as `(position, mass)`, from all the others.
+    pub fn forces(&self, bodies: &[(Vec2, f32)]) -> Vec<Vec2> {
+        match self.solver {
+            GravitySolver::Exact => bodies
+                .iter()
+                .enumerate()
+                .map(|(i, &(position, mass))| {
+                    bodies
+                        .iter()
+                        .enumerate()
+                        .filter(|&(j, _)| i != j)
+                        .map(|(_, &(other, other_mass))| {
+                            self.attraction(position, mass, other, other_mass)
+                        })
+                        .sum()
+                })
+                .collect(),
+            GravitySolver::BarnesHut { theta } => {
+                let tree = QuadTree::new(bodies.iter().copied());
+                bodies
+                    .iter()
+                    .map(|&(position, mass)| tree.force_on(position, mass, self, theta))
+                    .collect()
+            }
+        }
+    }
+}
+
+struct Node {
+    bounds: Rect,
+    mass: f32,
+    /// Mass-weighted sum of positions; divide by `mass` for the center of mass.
+    moment: Vec2,
+    /// Index of the first of four consecutive children.
+    children: Option<usize>,
+    /// The bodies of a leaf: one, or several that met at `MAX_DEPTH`.
+    bodies: Vec<(Vec2, f32)>,
+}
+
+impl Node {
+    fn new(bounds: Rect) -> Self {
+        Self {
+            bounds,
+            mass: 0.,
+            moment: Vec2::ZERO,
+            children: None,
+            bodies: Vec::new(),
+        }
+    }
+
+    fn center_of_mass(&self) -> Vec2 {
+        self.moment / self.mass
+    }
+}
+
+/// Barnes–Hut quadtree of point masses.
+pub struct QuadTree {
+    nodes: Vec<Node>,
+}
+
+impl QuadTree {
+    /// Coincident bodies stop subdividing here and share a leaf.
+    const MAX_DEPTH: u32 = 24;
+
+    pub fn new(bodies: impl IntoIterator<Item = (Vec2, f32)>) -> Self {
+        let bodies: Vec<_> = bodies.into_iter().filter(|&(_, mass)| mass > 0.).collect();
+
+        let bounds = bodies
+            .iter()
+            .fold(None, |bounds: Option<Rect>, &(position, _)| {
+                Some(
+                    bounds.map_or(Rect::from_center_size(position, Vec2::ZERO), |bounds| {
+                        bounds.union_point(position)
+                    }),
+                )
+            })
+            .unwrap_or_default();
+        let side = bounds.size().max_element().max(f32::EPSILON);
+        let root = Rect::from_center_size(bounds.center(), Vec2::splat(side));
+
+        let mut tree = Self {
+            nodes: vec![Node::new(root)],
+        };
+        for (position, mass) in bodies {
+            tree.insert(0, position, mass, 0);
+        }
+
+        tree
+    }
+
+    fn insert(&mut self, index: usize, position: Vec2, mass: f32, depth: u32) {
+        let node = &mut self.nodes[index];
+        if node.children.is_none() {
+            if node.mass == 0. || depth >= Self::MAX_DEPTH {
+                node.bodies.push((position, mass));
+                node.mass += mass;
+                node.moment += position * mass;
+                return;
+            }
+
+            let existing = std::mem::take(&mut node.bodies);
+            self.subdivide(index);
+            for (existing_position, existing_mass) in existing {
+                let child = self.quadrant(index, existing_position);
+                self.insert(child, existing_position, existing_mass, depth + 1);
+            }
+        }
+
+        let node = &mut self.nodes[index];
+        node.mass += mass;
+        node.moment += position * mass;
+
+        let child = self.quadrant(index, position);
+        self.insert(child, position, mass, depth + 1);
+    }
+
+    fn subdivide(&mut self, index: usize) {
+        let bounds = self.nodes[index].bounds;
+        let center = bounds.center();
+        let first = self.nodes.len();
+
+        self.nodes.extend([
+            Node::new(Rect::from_corners(bounds.min, center)),
+            Node::new(Rect::new(center.x, bounds.min.y, bounds.max.x, center.y)),
+            Node::new(Rect::new(bounds.min.x, center.y, center.x, bounds.max.y)),
+            Node::new(Rect::from_corners(center, bounds.max)),
+        ]);
+        self.nodes[index].children = Some(first);
+    }
+
+    fn quadrant(&self, index: usize, position: Vec2) -> usize {
+        let node = &self.nodes[index];
+        let center = node.bounds.center();
+        let first = node.children.expect("quadrant of a leaf");
+
+        let right = (position.x >= center.x) as usize;
+        let top = (position.y >= center.y) as usize;
+        first + right + top * 2
+    }
+
+    /// Approximate net force on a body at `position` from everything in the
+    /// tree. Leaves are summed body by body and a body feels no force from
+    /// one at its own position, so bodies in the tree don't attract themselves.
+    pub fn force_on(
+        &self,
+        position: Vec2,
+        mass: f32,
+        gravitation: &Gravitation,
+        theta: f32,
+    ) -> Vec2 {
+        let mut force = Vec2::ZERO;
+        let mut stack = vec![0];
+
+        while let Some(index) = stack.pop() {
+            let node = &self.nodes[index];
+            if node.mass == 0. {
+                continue;
+            }
+
+            let center_of_mass = node.center_of_mass();
+            match node.children {
+                // A node holding the body itself is always opened, or with a
+                // large `theta` the body would feel part of its own mass.
+                Some(first)
+                    if node.bounds.contains(position)
+                        || node.bounds.width() >= theta * center_of_mass.distance(position) =>
+                {
+                    stack.extend(first..first + 4);
+                }
+                Some(_) => {
+                    force += gravitation.attraction(position, mass, center_of_mass, node.mass);
+                }
+                // Use the exact positions in leaves, rounding in the center
+                // of mass would make a body attract itself.
+                None => {
+                    for &(other, other_mass) in &node.bodies {
+                        force += gravitation.attraction(position, mass, other, other_mass);
+                    }
+                }
+            }
+        }
+
+        force
+    }
+}
+
+pub struct GravityPlugin;
+
+impl Plugin for GravityPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<Gravitation>();
+        app.add_systems(FixedUpdate, Self::attract.before(PhysicsSet));
+    }
+}
+
+impl GravityPlugin {
+    fn attract(
+        gravitation: Res<Gravitation>,
+        attractors: Query<(Entity, &Position, &Mass), With<Attractor>>,
+        mut bodies: Query<(Entity, &Position, &Mass, &mut Forces)>,
+    ) {
+        match gravitation.solver {
+            GravitySolver::Exact => {
+                bodies
+                    .par_iter_mut()
+                    .for_each(|(entity, position, mass, mut forces)| {
+                        for (other, other_position, other_mass) in &attractors {
+                            if other != entity {
+                                forces.apply_force(gravitation.attraction(
+                                    position.0,
+                                    mass.0,
+                                    other_position.0,
+                                    other_mass.0,
+                                ));
+                            }
+                        }
+                    });
+            }
+            GravitySolver::BarnesHut { theta } => {
+                let tree = QuadTree::new(
+                    attractors
+                        .iter()
+                        .map(|(_, position, mass)| (position.0, mass.0)),
+                );
+                bodies
+                    .par_iter_mut()
+                    .for_each(|(_, position, mass, mut forces)| {
+                        forces.apply_force(tree.force_on(position.0, mass.0, &gravitation, theta));
+                    });
+            }
+        }
+    }
+}
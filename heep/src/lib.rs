@@ -1,3 +1,37 @@
+mod bounds;
 mod components;
+mod flocking;
+mod flowfield;
+mod ga;
+mod gravity;
+mod lsystem;
+mod nn;
+mod noise;
+mod oscillation;
+mod particles;
+mod path;
+mod physics;
+mod projection;
+mod resistance;
+mod sketch;
+mod spatial;
+mod steering;
 
+pub use bounds::*;
 pub use components::*;
+pub use flocking::*;
+pub use flowfield::*;
+pub use ga::*;
+pub use gravity::*;
+pub use lsystem::*;
+pub use nn::*;
+pub use noise::*;
+pub use oscillation::*;
+pub use particles::*;
+pub use path::*;
+pub use physics::*;
+pub use projection::*;
+pub use resistance::*;
+pub use sketch::*;
+pub use spatial::*;
+pub use steering::*;
@@ -0,0 +1,208 @@
+use std::collections::HashMap;
+
+use bevy::{
+    prelude::*,
+    render::{mesh::PrimitiveTopology, render_asset::RenderAssetUsages},
+};
+use bevy_turborand::TurboRand;
+
+/// A Lindenmayer system: an axiom rewritten in parallel by production rules
+/// each generation. A symbol with several productions picks one at random,
+/// in proportion to their weights. Symbols without productions are copied
+/// unchanged.
+#[derive(Clone, Debug, Default)]
+pub struct LSystem {
+    pub axiom: String,
+    productions: HashMap<char, Vec<(String, f32)>>,
+}
+
+impl LSystem {
+    pub fn new(axiom: &str) -> Self {
+        Self {
+            axiom: axiom.to_string(),
+            productions: HashMap::new(),
+        }
+    }
+
+    pub fn with_rule(self, predecessor: char, successor: &str) -> Self {
+        self.with_weighted_rule(predecessor, successor, 1.)
+    }
+
+    /// Adds a production chosen with probability `weight` over the summed
+    /// weights of the other productions for `predecessor`.
+    pub fn with_weighted_rule(mut self, predecessor: char, successor: &str, weight: f32) -> Self {
+        self.productions
+            .entry(predecessor)
+            .or_default()
+            .push((successor.to_string(), weight));
+        self
+    }
+
+    pub fn expand(&self, generations: usize, rng: &impl TurboRand) -> String {
+        (0..generations).fold(self.axiom.clone(), |current, _| {
+            let mut next = String::with_capacity(current.len() * 2);
+            for symbol in current.chars() {
+                match self.productions.get(&symbol) {
+                    Some(productions) => next.push_str(Self::choose(productions, rng)),
+                    None => next.push(symbol),
+                }
+            }
+            next
+        })
+    }
+
+    fn choose<'a>(productions: &'a [(String, f32)], rng: &impl TurboRand) -> &'a str {
+        if let [(successor, _)] = productions {
+            return successor;
+        }
+
+        let total: f32 = productions.iter().map(|(_, weight)| weight).sum();
+        let mut pick = rng.f32() * total;
+        for (successor, weight) in productions {
+            if pick < *weight {
+                return successor;
+            }
+            pick -= weight;
+        }
+        &productions[productions.len() - 1].0
+    }
+}
+
+/// A line drawn by the [`Turtle`]. `depth` is how many states were pushed
+/// when it was drawn, e.g. to thin out the branches of a plant.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Segment {
+    pub start: Vec2,
+    pub end: Vec2,
+    pub depth: usize,
+}
+
+/// Interprets an expanded [`LSystem`] as drawing commands:
+///
+/// - symbols in `draw` move forward drawing a line, symbols in `skip` move
+///   forward without drawing
+/// - `+` and `-` turn counter-clockwise and clockwise by `angle`, `|` turns
+///   around
+/// - `[` and `]` push and pop the position, heading, length and angle
+/// - `>` and `<` multiply and divide the length by `length_scale`
+/// - `)` and `(` multiply and divide the angle by `angle_scale`
+///
+/// Every other symbol is ignored.
+#[derive(Clone, Debug)]
+pub struct Turtle {
+    pub position: Vec2,
+    /// Initial heading in radians, counter-clockwise from +x.
+    pub heading: f32,
+    pub length: f32,
+    pub angle: f32,
+    pub length_scale: f32,
+    pub angle_scale: f32,
+    pub draw: String,
+    pub skip: String,
+}
+
+impl Default for Turtle {
+    fn default() -> Self {
+        Self {
+            position: Vec2::ZERO,
+            heading: 0.,
+            length: 1.,
+            angle: 90_f32.to_radians(),
+            length_scale: 0.5,
+            angle_scale: 0.9,
+            draw: "FG".to_string(),
+            skip: "f".to_string(),
+        }
+    }
+}
+
+#[derive(Clone, Copy)]
+struct TurtleState {
+    position: Vec2,
+    heading: f32,
+    length: f32,
+    angle: f32,
+}
+
+impl Turtle {
+    pub fn new(length: f32, angle: f32) -> Self {
+        Self {
+            length,
+            angle,
+            ..Default::default()
+        }
+    }
+
+    pub fn with_position(mut self, position: Vec2, heading: f32) -> Self {
+        self.position = position;
+        self.heading = heading;
+        self
+    }
+
+    pub fn with_scales(mut self, length_scale: f32, angle_scale: f32) -> Self {
+        self.length_scale = length_scale;
+        self.angle_scale = angle_scale;
+        self
+    }
+
+    pub fn with_symbols(mut self, draw: &str, skip: &str) -> Self {
+        self.draw = draw.to_string();
+        self.skip = skip.to_string();
+        self
+    }
+
+    pub fn interpret(&self, commands: &str) -> Vec<Segment> {
+        let mut state = TurtleState {
+            position: self.position,
+            heading: self.heading,
+            length: self.length,
+            angle: self.angle,
+        };
+        let mut stack = Vec::new();
+        let mut segments = Vec::new();
+
+        for command in commands.chars() {
+            match command {
+                '+' => state.heading += state.angle,
+                '-' => state.heading -= state.angle,
+                '|' => state.heading += std::f32::consts::PI,
+                '[' => stack.push(state),
+                ']' => {
+                    if let Some(saved) = stack.pop() {
+                        state = saved;
+                    }
+                }
+                '>' => state.length *= self.length_scale,
+                '<' => state.length /= self.length_scale,
+                ')' => state.angle *= self.angle_scale,
+                '(' => state.angle /= self.angle_scale,
+                c if self.draw.contains(c) || self.skip.contains(c) => {
+                    let end = state.position + Vec2::from_angle(state.heading) * state.length;
+                    if self.draw.contains(c) {
+                        segments.push(Segment {
+                            start: state.position,
+                            end,
+                            depth: stack.len(),
+                        });
+                    }
+                    state.position = end;
+                }
+                _ => {}
+            }
+        }
+
+        segments
+    }
+}
+
+/// Builds a line list mesh from `segments`, for use with a 2D material.
+pub fn line_mesh(segments: &[Segment]) -> Mesh {
+    let positions: Vec<[f32; 3]> = segments
+        .iter()
+        .flat_map(|segment| [segment.start.extend(0.), segment.end.extend(0.)])
+        .map(|point| point.to_array())
+        .collect();
+
+    Mesh::new(PrimitiveTopology::LineList, RenderAssetUsages::default())
+        .with_inserted_attribute(Mesh::ATTRIBUTE_POSITION, positions)
+}
@@ -0,0 +1,387 @@
+use std::{fmt, str::FromStr};
+
+use bevy_turborand::TurboRand;
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Activation {
+    #[default]
+    Sigmoid,
+    Tanh,
+    Relu,
+    Identity,
+}
+
+impl Activation {
+    pub fn apply(self, x: f32) -> f32 {
+        match self {
+            Self::Sigmoid => 1. / (1. + (-x).exp()),
+            Self::Tanh => x.tanh(),
+            Self::Relu => x.max(0.),
+            Self::Identity => x,
+        }
+    }
+
+    /// Derivative expressed in terms of the activation's output `y`, which
+    /// is what backpropagation has at hand.
+    pub fn derivative(self, y: f32) -> f32 {
+        match self {
+            Self::Sigmoid => y * (1. - y),
+            Self::Tanh => 1. - y * y,
+            Self::Relu => (y > 0.) as u8 as f32,
+            Self::Identity => 1.,
+        }
+    }
+
+    fn name(self) -> &'static str {
+        match self {
+            Self::Sigmoid => "sigmoid",
+            Self::Tanh => "tanh",
+            Self::Relu => "relu",
+            Self::Identity => "identity",
+        }
+    }
+}
+
+/// A single neuron with a sign activation, trained with the perceptron
+/// learning rule. Only separates linearly separable classes.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Perceptron {
+    pub weights: Vec<f32>,
+    pub bias: f32,
+    pub learning_rate: f32,
+}
+
+impl Perceptron {
+    pub fn new(inputs: usize, learning_rate: f32, rng: &impl TurboRand) -> Self {
+        Self {
+            weights: (0..inputs).map(|_| rng.f32_normalized()).collect(),
+            bias: rng.f32_normalized(),
+            learning_rate,
+        }
+    }
+
+    /// `1.` or `-1.` depending on which side of the decision boundary
+    /// `inputs` falls.
+    pub fn predict(&self, inputs: &[f32]) -> f32 {
+        let sum: f32 = self.weights.iter().zip(inputs).map(|(w, x)| w * x).sum();
+        if sum + self.bias >= 0. {
+            1.
+        } else {
+            -1.
+        }
+    }
+
+    /// Nudges the weights towards `target` (`1.` or `-1.`) and returns the
+    /// error before training.
+    pub fn train(&mut self, inputs: &[f32], target: f32) -> f32 {
+        let error = target - self.predict(inputs);
+        for (weight, input) in self.weights.iter_mut().zip(inputs) {
+            *weight += error * input * self.learning_rate;
+        }
+        self.bias += error * self.learning_rate;
+        error
+    }
+}
+
+/// A fully connected layer. `weights` holds one row of `inputs` weights
+/// per output.
+#[derive(Clone, Debug, PartialEq)]
+pub struct DenseLayer {
+    pub inputs: usize,
+    pub weights: Vec<f32>,
+    pub biases: Vec<f32>,
+    pub activation: Activation,
+}
+
+impl DenseLayer {
+    pub fn outputs(&self) -> usize {
+        self.biases.len()
+    }
+
+    /// Outputs the activated biases alone when the layer has no inputs.
+    pub fn forward(&self, input: &[f32]) -> Vec<f32> {
+        self.biases
+            .iter()
+            .enumerate()
+            .map(|(o, bias)| {
+                let row = &self.weights[o * self.inputs..(o + 1) * self.inputs];
+                let sum: f32 = row.iter().zip(input).map(|(w, x)| w * x).sum();
+                self.activation.apply(sum + bias)
+            })
+            .collect()
+    }
+}
+
+/// A feed-forward multilayer perceptron trained by backpropagation with
+/// stochastic gradient descent on the squared error.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Network {
+    pub inputs: usize,
+    pub layers: Vec<DenseLayer>,
+    pub learning_rate: f32,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum NetworkError {
+    InvalidLine { line: usize },
+    UnknownActivation(String),
+    WrongParameterCount { expected: usize, found: usize },
+}
+
+impl fmt::Display for NetworkError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidLine { line } => write!(f, "line {line}: malformed network"),
+            Self::UnknownActivation(name) => write!(f, "unknown activation `{name}`"),
+            Self::WrongParameterCount { expected, found } => {
+                write!(f, "expected {expected} parameters, found {found}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for NetworkError {}
+
+impl Network {
+    pub fn new(inputs: usize) -> Self {
+        Self {
+            inputs,
+            layers: Vec::new(),
+            learning_rate: 0.1,
+        }
+    }
+
+    /// Appends a layer of `size` neurons with zeroed weights. Call
+    /// [`Network::randomized`] once every layer is added.
+    pub fn with_layer(mut self, size: usize, activation: Activation) -> Self {
+        let inputs = self.outputs();
+        self.layers.push(DenseLayer {
+            inputs,
+            weights: vec![0.; inputs * size],
+            biases: vec![0.; size],
+            activation,
+        });
+        self
+    }
+
+    pub fn with_learning_rate(mut self, learning_rate: f32) -> Self {
+        self.learning_rate = learning_rate;
+        self
+    }
+
+    /// Draws every weight uniformly from ±1/√inputs of its layer, and every
+    /// bias from ±1.
+    pub fn randomized(mut self, rng: &impl TurboRand) -> Self {
+        for layer in &mut self.layers {
+            let scale = 1. / (layer.inputs.max(1) as f32).sqrt();
+            layer
+                .weights
+                .iter_mut()
+                .for_each(|w| *w = rng.f32_normalized() * scale);
+            layer
+                .biases
+                .iter_mut()
+                .for_each(|b| *b = rng.f32_normalized());
+        }
+        self
+    }
+
+    pub fn outputs(&self) -> usize {
+        self.layers.last().map_or(self.inputs, DenseLayer::outputs)
+    }
+
+    pub fn forward(&self, input: &[f32]) -> Vec<f32> {
+        self.layers
+            .iter()
+            .fold(input.to_vec(), |activations, layer| {
+                layer.forward(&activations)
+            })
+    }
+
+    /// Runs one step of gradient descent towards `target` and returns the
+    /// squared error before the step.
+    pub fn train(&mut self, input: &[f32], target: &[f32]) -> f32 {
+        let mut activations = vec![input.to_vec()];
+        for layer in &self.layers {
+            let next = layer.forward(activations.last().unwrap());
+            activations.push(next);
+        }
+
+        let output = activations.last().unwrap();
+        let loss = output
+            .iter()
+            .zip(target)
+            .map(|(y, t)| (y - t).powi(2))
+            .sum();
+        let mut errors: Vec<f32> = output.iter().zip(target).map(|(y, t)| y - t).collect();
+
+        for (index, layer) in self.layers.iter_mut().enumerate().rev() {
+            let input = &activations[index];
+            let output = &activations[index + 1];
+            let deltas: Vec<f32> = errors
+                .iter()
+                .zip(output)
+                .map(|(error, y)| error * layer.activation.derivative(*y))
+                .collect();
+
+            errors = (0..layer.inputs)
+                .map(|i| {
+                    deltas
+                        .iter()
+                        .enumerate()
+                        .map(|(o, delta)| delta * layer.weights[o * layer.inputs + i])
+                        .sum()
+                })
+                .collect();
+
+            for (o, delta) in deltas.iter().enumerate() {
+                for (i, x) in input.iter().take(layer.inputs).enumerate() {
+                    layer.weights[o * layer.inputs + i] -= self.learning_rate * delta * x;
+                }
+                layer.biases[o] -= self.learning_rate * delta;
+            }
+        }
+
+        loss
+    }
+
+    pub fn parameter_count(&self) -> usize {
+        self.layers
+            .iter()
+            .map(|layer| layer.weights.len() + layer.biases.len())
+            .sum()
+    }
+
+    /// Every weight and bias, layer by layer, e.g. to use as a genome.
+    pub fn parameters(&self) -> Vec<f32> {
+        self.layers
+            .iter()
+            .flat_map(|layer| layer.weights.iter().chain(&layer.biases))
+            .copied()
+            .collect()
+    }
+
+    pub fn set_parameters(&mut self, parameters: &[f32]) -> Result<(), NetworkError> {
+        if parameters.len() != self.parameter_count() {
+            return Err(NetworkError::WrongParameterCount {
+                expected: self.parameter_count(),
+                found: parameters.len(),
+            });
+        }
+
+        let mut parameters = parameters.iter().copied();
+        for layer in &mut self.layers {
+            for value in layer.weights.iter_mut().chain(&mut layer.biases) {
+                *value = parameters.next().unwrap();
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Serializes the shape and parameters as text:
+///
+/// ```text
+/// inputs 2
+/// learning_rate 0.1
+/// layer 4 tanh
+/// <weights>
+/// <biases>
+/// layer 1 sigmoid
+/// ...
+/// ```
+///
+/// A layer with no weights or biases writes an empty line for them, which
+/// [`FromStr`] reads back as empty.
+impl fmt::Display for Network {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let join = |values: &[f32]| {
+            values
+                .iter()
+                .map(f32::to_string)
+                .collect::<Vec<_>>()
+                .join(" ")
+        };
+
+        writeln!(f, "inputs {}", self.inputs)?;
+        writeln!(f, "learning_rate {}", self.learning_rate)?;
+        for layer in &self.layers {
+            writeln!(f, "layer {} {}", layer.outputs(), layer.activation.name())?;
+            writeln!(f, "{}", join(&layer.weights))?;
+            writeln!(f, "{}", join(&layer.biases))?;
+        }
+        Ok(())
+    }
+}
+
+impl FromStr for Network {
+    type Err = NetworkError;
+
+    fn from_str(source: &str) -> Result<Self, Self::Err> {
+        let lines: Vec<(usize, Vec<&str>)> = source
+            .lines()
+            .enumerate()
+            .map(|(index, line)| (index + 1, line.split_whitespace().collect()))
+            .filter(|(_, words): &(usize, Vec<&str>)| !words.is_empty())
+            .collect();
+        let invalid = |line: usize| NetworkError::InvalidLine { line };
+        let field = |index: usize, key: &str| -> Result<&str, NetworkError> {
+            match lines.get(index) {
+                Some((_, words)) if words.len() == 2 && words[0] == key => Ok(words[1]),
+                Some(&(line, _)) => Err(invalid(line)),
+                None => Err(invalid(0)),
+            }
+        };
+        // Empty lists are written as blank lines, which were skipped above,
+        // so they take no line and `next` stays put.
+        let floats = |next: &mut usize, count: usize| -> Result<Vec<f32>, NetworkError> {
+            if count == 0 {
+                return Ok(Vec::new());
+            }
+            let (line, words) = lines.get(*next).ok_or(invalid(0))?;
+            *next += 1;
+            let values: Vec<f32> = words
+                .iter()
+                .map(|word| word.parse().map_err(|_| invalid(*line)))
+                .collect::<Result<_, _>>()?;
+            if values.len() == count {
+                Ok(values)
+            } else {
+                Err(invalid(*line))
+            }
+        };
+
+        let inputs = field(0, "inputs")?
+            .parse()
+            .map_err(|_| invalid(lines[0].0))?;
+        let mut network = Self::new(inputs);
+        network.learning_rate = field(1, "learning_rate")?
+            .parse()
+            .map_err(|_| invalid(lines[1].0))?;
+
+        let mut next = 2;
+        while let Some((line, words)) = lines.get(next) {
+            next += 1;
+            let (size, activation) = match words.as_slice() {
+                ["layer", size, activation] => (
+                    size.parse::<usize>().map_err(|_| invalid(*line))?,
+                    match *activation {
+                        "sigmoid" => Activation::Sigmoid,
+                        "tanh" => Activation::Tanh,
+                        "relu" => Activation::Relu,
+                        "identity" => Activation::Identity,
+                        other => return Err(NetworkError::UnknownActivation(other.to_string())),
+                    },
+                ),
+                _ => return Err(invalid(*line)),
+            };
+
+            network = network.with_layer(size, activation);
+            let layer = network.layers.last_mut().unwrap();
+            layer.weights = floats(&mut next, layer.weights.len())?;
+            layer.biases = floats(&mut next, size)?;
+        }
+
+        Ok(network)
+    }
+}
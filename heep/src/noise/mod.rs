@@ -0,0 +1,189 @@
+use bevy::prelude::*;
+use bevy_turborand::{rng::Rng, SeededCore, TurboRand};
+
+/// Seeded gradient (Perlin) noise. Every sample lies roughly in `[-1, 1]`.
+#[derive(Resource, Clone)]
+pub struct Perlin {
+    permutation: Box<[u8; 512]>,
+}
+
+/// Fractal Brownian motion settings used to layer octaves of [`Perlin`] noise.
+#[derive(Clone, Copy)]
+pub struct Fbm {
+    pub octaves: u32,
+    /// Frequency multiplier between successive octaves.
+    pub lacunarity: f32,
+    /// Amplitude multiplier between successive octaves.
+    pub persistence: f32,
+}
+
+impl Default for Fbm {
+    fn default() -> Self {
+        Self {
+            octaves: 4,
+            lacunarity: 2.,
+            persistence: 0.5,
+        }
+    }
+}
+
+impl Fbm {
+    fn sum(&self, sample: impl Fn(f32) -> f32) -> f32 {
+        let mut total = 0.;
+        let mut frequency = 1.;
+        let mut amplitude = 1.;
+        let mut max = 0.;
+
+        for _ in 0..self.octaves.max(1) {
+            total += sample(frequency) * amplitude;
+            max += amplitude;
+            frequency *= self.lacunarity;
+            amplitude *= self.persistence;
+        }
+
+        total / max
+    }
+}
+
+impl Perlin {
+    pub fn new(seed: u64) -> Self {
+        let mut table: [u8; 256] = std::array::from_fn(|i| i as u8);
+        Rng::with_seed(seed).shuffle(&mut table);
+
+        let mut permutation = Box::new([0; 512]);
+        for (i, value) in permutation.iter_mut().enumerate() {
+            *value = table[i & 255];
+        }
+
+        Self { permutation }
+    }
+
+    fn hash(&self, i: i32) -> usize {
+        self.permutation[(i & 255) as usize] as usize
+    }
+
+    pub fn noise1(&self, x: f32) -> f32 {
+        let xi = x.floor() as i32;
+        let x = x - x.floor();
+        let u = fade(x);
+
+        let a = grad1(self.hash(xi), x);
+        let b = grad1(self.hash(xi + 1), x - 1.);
+
+        lerp(a, b, u) * 2.
+    }
+
+    pub fn noise2(&self, point: Vec2) -> f32 {
+        let cell = point.floor();
+        let (xi, yi) = (cell.x as i32, cell.y as i32);
+        let Vec2 { x, y } = point - cell;
+        let (u, v) = (fade(x), fade(y));
+
+        let aa = self.hash(self.hash(xi) as i32 + yi);
+        let ab = self.hash(self.hash(xi) as i32 + yi + 1);
+        let ba = self.hash(self.hash(xi + 1) as i32 + yi);
+        let bb = self.hash(self.hash(xi + 1) as i32 + yi + 1);
+
+        lerp(
+            lerp(grad2(aa, x, y), grad2(ba, x - 1., y), u),
+            lerp(grad2(ab, x, y - 1.), grad2(bb, x - 1., y - 1.), u),
+            v,
+        )
+    }
+
+    pub fn noise3(&self, point: Vec3) -> f32 {
+        let cell = point.floor();
+        let (xi, yi, zi) = (cell.x as i32, cell.y as i32, cell.z as i32);
+        let Vec3 { x, y, z } = point - cell;
+        let (u, v, w) = (fade(x), fade(y), fade(z));
+
+        let a = self.hash(xi) as i32 + yi;
+        let aa = self.hash(a) as i32 + zi;
+        let ab = self.hash(a + 1) as i32 + zi;
+        let b = self.hash(xi + 1) as i32 + yi;
+        let ba = self.hash(b) as i32 + zi;
+        let bb = self.hash(b + 1) as i32 + zi;
+
+        lerp(
+            lerp(
+                lerp(
+                    grad3(self.hash(aa), x, y, z),
+                    grad3(self.hash(ba), x - 1., y, z),
+                    u,
+                ),
+                lerp(
+                    grad3(self.hash(ab), x, y - 1., z),
+                    grad3(self.hash(bb), x - 1., y - 1., z),
+                    u,
+                ),
+                v,
+            ),
+            lerp(
+                lerp(
+                    grad3(self.hash(aa + 1), x, y, z - 1.),
+                    grad3(self.hash(ba + 1), x - 1., y, z - 1.),
+                    u,
+                ),
+                lerp(
+                    grad3(self.hash(ab + 1), x, y - 1., z - 1.),
+                    grad3(self.hash(bb + 1), x - 1., y - 1., z - 1.),
+                    u,
+                ),
+                v,
+            ),
+            w,
+        )
+    }
+
+    pub fn fbm1(&self, x: f32, fbm: Fbm) -> f32 {
+        fbm.sum(|frequency| self.noise1(x * frequency))
+    }
+
+    pub fn fbm2(&self, point: Vec2, fbm: Fbm) -> f32 {
+        fbm.sum(|frequency| self.noise2(point * frequency))
+    }
+
+    pub fn fbm3(&self, point: Vec3, fbm: Fbm) -> f32 {
+        fbm.sum(|frequency| self.noise3(point * frequency))
+    }
+}
+
+fn fade(t: f32) -> f32 {
+    t * t * t * (t * (t * 6. - 15.) + 10.)
+}
+
+fn lerp(a: f32, b: f32, t: f32) -> f32 {
+    a + (b - a) * t
+}
+
+fn grad1(hash: usize, x: f32) -> f32 {
+    let gradient = (hash & 15) as f32 / 7.5 - 1.;
+    gradient * x
+}
+
+fn grad2(hash: usize, x: f32, y: f32) -> f32 {
+    match hash & 7 {
+        0 => x + y,
+        1 => -x + y,
+        2 => x - y,
+        3 => -x - y,
+        4 => x,
+        5 => -x,
+        6 => y,
+        _ => -y,
+    }
+}
+
+fn grad3(hash: usize, x: f32, y: f32, z: f32) -> f32 {
+    let h = hash & 15;
+    let u = if h < 8 { x } else { y };
+    let v = match h {
+        0..=3 => y,
+        12 | 14 => x,
+        _ => z,
+    };
+
+    let u = if h & 1 == 0 { u } else { -u };
+    let v = if h & 2 == 0 { v } else { -v };
+    u + v
+}
@@ -0,0 +1,269 @@
+use std::f32::consts::TAU;
+
+use bevy::prelude::*;
+
+use crate::{Forces, Integrator, PhysicsSet, Position, Velocity};
+
+/// One end of a [`Spring`].
+#[derive(Clone, Copy)]
+pub enum SpringEnd {
+    /// A body with a `Position`. It is pulled on if it also has `Forces`.
+    Entity(Entity),
+    /// A fixed point in the world.
+    Anchor(Vec2),
+}
+
+/// Hooke's law spring connecting two ends. Lives on its own entity.
+#[derive(Component, Clone, Copy)]
+pub struct Spring {
+    pub a: SpringEnd,
+    pub b: SpringEnd,
+    pub rest_length: f32,
+    pub stiffness: f32,
+    /// Opposes the ends' relative velocity along the spring.
+    pub damping: f32,
+    pub min_length: Option<f32>,
+    pub max_length: Option<f32>,
+}
+
+impl Spring {
+    pub fn new(a: SpringEnd, b: SpringEnd, rest_length: f32, stiffness: f32) -> Self {
+        Self {
+            a,
+            b,
+            rest_length,
+            stiffness,
+            damping: 0.,
+            min_length: None,
+            max_length: None,
+        }
+    }
+
+    pub fn with_damping(mut self, damping: f32) -> Self {
+        self.damping = damping;
+        self
+    }
+
+    pub fn with_length_limits(mut self, min_length: f32, max_length: f32) -> Self {
+        self.min_length = Some(min_length);
+        self.max_length = Some(max_length);
+        self
+    }
+
+    /// Force on end `b`. End `a` feels the opposite.
+    pub fn force(&self, a: Vec2, a_velocity: Vec2, b: Vec2, b_velocity: Vec2) -> Vec2 {
+        let offset = b - a;
+        let direction = offset.normalize_or_zero();
+        let stretch = offset.length() - self.rest_length;
+        let closing_speed = (b_velocity - a_velocity).dot(direction);
+
+        -direction * (self.stiffness * stretch + self.damping * closing_speed)
+    }
+
+    pub fn potential_energy(&self, a: Vec2, b: Vec2) -> f32 {
+        let stretch = a.distance(b) - self.rest_length;
+        0.5 * self.stiffness * stretch * stretch
+    }
+}
+
+/// Simple pendulum swinging from `pivot`. Drives the entity's `Position`.
+#[derive(Component, Clone, Copy)]
+pub struct Pendulum {
+    pub pivot: Vec2,
+    pub length: f32,
+    /// Angle from straight down in radians, counter-clockwise.
+    pub angle: f32,
+    pub angular_velocity: f32,
+    pub gravity: f32,
+    pub damping: f32,
+}
+
+impl Pendulum {
+    pub fn new(pivot: Vec2, length: f32, angle: f32, gravity: f32) -> Self {
+        Self {
+            pivot,
+            length,
+            angle,
+            angular_velocity: 0.,
+            gravity,
+            damping: 0.,
+        }
+    }
+
+    pub fn bob(&self) -> Vec2 {
+        self.pivot + Vec2::new(self.angle.sin(), -self.angle.cos()) * self.length
+    }
+
+    /// Period of small swings, `2π√(L/g)`.
+    pub fn small_angle_period(&self) -> f32 {
+        TAU * (self.length / self.gravity).sqrt()
+    }
+
+    /// Energy per unit mass, measured from the pivot's height.
+    pub fn energy(&self) -> f32 {
+        let speed = self.angular_velocity * self.length;
+        0.5 * speed * speed - self.gravity * self.length * self.angle.cos()
+    }
+
+    pub fn step(&mut self, integrator: Integrator, dt: f32) {
+        let (gravity, length, damping) = (self.gravity, self.length, self.damping);
+        (self.angle, self.angular_velocity) = integrator.step(
+            self.angle,
+            self.angular_velocity,
+            dt,
+            |angle: f32, angular_velocity| {
+                -gravity / length * angle.sin() - damping * angular_velocity
+            },
+        );
+    }
+}
+
+/// Simple harmonic motion around `center`. Drives the entity's `Position`.
+#[derive(Component, Clone, Copy)]
+pub struct Oscillator {
+    pub center: Vec2,
+    pub amplitude: Vec2,
+    /// Seconds per full cycle.
+    pub period: f32,
+    /// Offset into the cycle in radians.
+    pub phase: f32,
+}
+
+impl Oscillator {
+    pub fn position(&self, elapsed: f32) -> Vec2 {
+        self.center + self.amplitude * (TAU * elapsed / self.period + self.phase).sin()
+    }
+}
+
+pub struct OscillationPlugin;
+
+impl Plugin for OscillationPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<Integrator>();
+        app.add_systems(
+            FixedUpdate,
+            (
+                Self::apply_springs.before(PhysicsSet),
+                (
+                    Self::constrain_springs,
+                    Self::swing_pendulums,
+                    Self::oscillate,
+                )
+                    .after(PhysicsSet),
+            ),
+        );
+    }
+}
+
+impl OscillationPlugin {
+    fn resolve(
+        end: SpringEnd,
+        bodies: &Query<(&Position, Option<&Velocity>)>,
+    ) -> Option<(Vec2, Vec2)> {
+        match end {
+            SpringEnd::Anchor(anchor) => Some((anchor, Vec2::ZERO)),
+            SpringEnd::Entity(entity) => bodies.get(entity).ok().map(|(position, velocity)| {
+                (
+                    position.0,
+                    velocity.map_or(Vec2::ZERO, |velocity| velocity.0),
+                )
+            }),
+        }
+    }
+
+    fn apply_springs(
+        springs: Query<&Spring>,
+        bodies: Query<(&Position, Option<&Velocity>)>,
+        mut forces: Query<&mut Forces>,
+    ) {
+        for spring in &springs {
+            let (Some((a, a_velocity)), Some((b, b_velocity))) = (
+                Self::resolve(spring.a, &bodies),
+                Self::resolve(spring.b, &bodies),
+            ) else {
+                continue;
+            };
+
+            let force = spring.force(a, a_velocity, b, b_velocity);
+            if let SpringEnd::Entity(entity) = spring.a {
+                if let Ok(mut forces) = forces.get_mut(entity) {
+                    forces.apply_force(-force);
+                }
+            }
+            if let SpringEnd::Entity(entity) = spring.b {
+                if let Ok(mut forces) = forces.get_mut(entity) {
+                    forces.apply_force(force);
+                }
+            }
+        }
+    }
+
+    /// Pulls the ends of springs outside their length limits back in range,
+    /// splitting the correction between movable ends.
+    fn constrain_springs(
+        springs: Query<&Spring>,
+        mut bodies: Query<(&mut Position, Option<&mut Velocity>)>,
+    ) {
+        for spring in &springs {
+            let position = |end| match end {
+                SpringEnd::Anchor(anchor) => Some(anchor),
+                SpringEnd::Entity(entity) => {
+                    bodies.get(entity).ok().map(|(position, _)| position.0)
+                }
+            };
+            let (Some(a), Some(b)) = (position(spring.a), position(spring.b)) else {
+                continue;
+            };
+
+            let offset = b - a;
+            let length = offset.length();
+            let target = length.clamp(
+                spring.min_length.unwrap_or(0.),
+                spring.max_length.unwrap_or(f32::INFINITY),
+            );
+            if length == target || length == 0. {
+                continue;
+            }
+
+            let direction = offset / length;
+            let movable = [spring.a, spring.b]
+                .iter()
+                .filter(|end| matches!(end, SpringEnd::Entity(_)))
+                .count() as f32;
+            let correction = direction * (target - length) / movable;
+
+            for (end, sign) in [(spring.a, -1.), (spring.b, 1.)] {
+                if let SpringEnd::Entity(entity) = end {
+                    if let Ok((mut position, velocity)) = bodies.get_mut(entity) {
+                        position.0 += correction * sign;
+                        // Drop the velocity that would carry the end further
+                        // past the limit.
+                        if let Some(mut velocity) = velocity {
+                            let along = velocity.0.dot(direction) * sign;
+                            if along * (length - target).signum() > 0. {
+                                velocity.0 -= direction * sign * along;
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    fn swing_pendulums(
+        time: Res<Time>,
+        integrator: Res<Integrator>,
+        mut pendulums: Query<(&mut Pendulum, &mut Position)>,
+    ) {
+        for (mut pendulum, mut position) in &mut pendulums {
+            pendulum.step(*integrator, time.delta_seconds());
+            position.0 = pendulum.bob();
+        }
+    }
+
+    fn oscillate(time: Res<Time>, mut oscillators: Query<(&Oscillator, &mut Position)>) {
+        for (oscillator, mut position) in &mut oscillators {
+            position.0 = oscillator.position(time.elapsed_seconds());
+        }
+    }
+}
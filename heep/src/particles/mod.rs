@@ -0,0 +1,218 @@
+use bevy::prelude::*;
+use bevy_turborand::{DelegatedRng, GlobalRng};
+
+use crate::{BodyBundle, PhysicsSet, Position, PreviousPosition, Velocity};
+
+/// Continuously emits [`Particle`]s from the emitter entity's `Position`.
+#[derive(Component, Clone)]
+pub struct Emitter {
+    /// Particles per second.
+    pub rate: f32,
+    /// Seconds each particle lives for.
+    pub lifespan: f32,
+    /// Mean direction of the initial velocity.
+    pub direction: Vec2,
+    /// Initial velocities are spread up to this many radians either side of
+    /// `direction`.
+    pub spread: f32,
+    pub min_speed: f32,
+    pub max_speed: f32,
+    pub color: Color,
+    pub size: f32,
+    /// Fractional particles carried over to the next step.
+    pending: f32,
+}
+
+impl Emitter {
+    pub fn new(rate: f32, lifespan: f32) -> Self {
+        Self {
+            rate,
+            lifespan,
+            direction: Vec2::Y,
+            spread: std::f32::consts::PI,
+            min_speed: 0.,
+            max_speed: 0.,
+            color: Color::WHITE,
+            size: 1.,
+            pending: 0.,
+        }
+    }
+
+    pub fn with_velocity(
+        mut self,
+        direction: Vec2,
+        spread: f32,
+        min_speed: f32,
+        max_speed: f32,
+    ) -> Self {
+        self.direction = direction;
+        self.spread = spread;
+        self.min_speed = min_speed;
+        self.max_speed = max_speed;
+        self
+    }
+
+    pub fn with_color(mut self, color: Color) -> Self {
+        self.color = color;
+        self
+    }
+
+    pub fn with_size(mut self, size: f32) -> Self {
+        self.size = size;
+        self
+    }
+}
+
+/// A live particle. Fades out over its lifespan and is then returned to the
+/// [`ParticlePool`].
+#[derive(Component, Clone, Copy)]
+pub struct Particle {
+    pub age: f32,
+    pub lifespan: f32,
+    color: Color,
+}
+
+/// Expired particle entities kept hidden for reuse instead of being despawned.
+/// Pooled entities lose their body components, so physics, bounds and the
+/// spatial index skip them until they are spawned again.
+#[derive(Resource)]
+pub struct ParticlePool {
+    free: Vec<Entity>,
+    /// Expired particles beyond this many free entities are despawned.
+    pub capacity: usize,
+}
+
+impl Default for ParticlePool {
+    fn default() -> Self {
+        Self {
+            free: Vec::new(),
+            capacity: 10_000,
+        }
+    }
+}
+
+impl ParticlePool {
+    pub fn free(&self) -> usize {
+        self.free.len()
+    }
+
+    /// Spawns a particle, reusing a pooled entity when one is free. Pooled
+    /// entities despawned by something else are dropped from the pool.
+    pub fn spawn(
+        &mut self,
+        commands: &mut Commands,
+        position: Vec2,
+        velocity: Vec2,
+        lifespan: f32,
+        color: Color,
+        size: f32,
+    ) -> Entity {
+        let particle = (
+            Particle {
+                age: 0.,
+                lifespan,
+                color,
+            },
+            Sprite {
+                color,
+                custom_size: Some(Vec2::splat(size)),
+                ..Default::default()
+            },
+            BodyBundle {
+                position: Position(position),
+                velocity: Velocity(velocity),
+                ..Default::default()
+            },
+            PreviousPosition(position),
+            Visibility::Visible,
+        );
+
+        while let Some(entity) = self.free.pop() {
+            if let Some(mut pooled) = commands.get_entity(entity) {
+                pooled.insert(particle);
+                return entity;
+            }
+        }
+
+        // Inserted over the defaults, a single bundle can't repeat `Sprite`.
+        commands
+            .spawn(SpriteBundle::default())
+            .insert(particle)
+            .id()
+    }
+
+    fn release(&mut self, commands: &mut Commands, entity: Entity) {
+        if self.free.len() < self.capacity {
+            commands
+                .entity(entity)
+                .remove::<(Particle, BodyBundle, PreviousPosition)>()
+                .insert(Visibility::Hidden);
+            self.free.push(entity);
+        } else {
+            commands.entity(entity).despawn_recursive();
+        }
+    }
+}
+
+pub struct ParticlePlugin;
+
+impl Plugin for ParticlePlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<ParticlePool>();
+        app.add_systems(
+            FixedUpdate,
+            (
+                Self::emit.before(PhysicsSet),
+                Self::age_particles.after(PhysicsSet),
+            ),
+        );
+    }
+}
+
+impl ParticlePlugin {
+    fn emit(
+        mut commands: Commands,
+        time: Res<Time>,
+        mut pool: ResMut<ParticlePool>,
+        mut rng: ResMut<GlobalRng>,
+        mut emitters: Query<(&mut Emitter, &Position)>,
+    ) {
+        for (mut emitter, position) in &mut emitters {
+            emitter.pending += emitter.rate * time.delta_seconds();
+            while emitter.pending >= 1. {
+                emitter.pending -= 1.;
+
+                let angle = (rng.f32() * 2. - 1.) * emitter.spread;
+                let speed = emitter.min_speed + rng.f32() * (emitter.max_speed - emitter.min_speed);
+                let velocity =
+                    Vec2::from_angle(angle).rotate(emitter.direction.normalize_or_zero()) * speed;
+
+                pool.spawn(
+                    &mut commands,
+                    position.0,
+                    velocity,
+                    emitter.lifespan,
+                    emitter.color,
+                    emitter.size,
+                );
+            }
+        }
+    }
+
+    fn age_particles(
+        mut commands: Commands,
+        time: Res<Time>,
+        mut pool: ResMut<ParticlePool>,
+        mut particles: Query<(Entity, &mut Particle, &mut Sprite)>,
+    ) {
+        for (entity, mut particle, mut sprite) in &mut particles {
+            particle.age += time.delta_seconds();
+            if particle.age >= particle.lifespan {
+                pool.release(&mut commands, entity);
+            } else {
+                let alpha = particle.color.alpha() * (1. - particle.age / particle.lifespan);
+                sprite.color = particle.color.with_alpha(alpha);
+            }
+        }
+    }
+}
@@ -0,0 +1,227 @@
+use std::{fmt, num::ParseFloatError, str::FromStr};
+
+use bevy::{
+    asset::{io::Reader, AssetLoader, AsyncReadExt, LoadContext},
+    prelude::*,
+};
+
+use crate::{Forces, PhysicsSet, Position, Vehicle, Velocity};
+
+/// A polyline with a width for vehicles to follow. Loaded from `.path` files:
+///
+/// ```text
+/// # comments and blank lines are ignored
+/// radius 4
+/// loop
+/// -60 -20
+/// 0 30
+/// 60 -20
+/// ```
+///
+/// Every other line is a point. Without `loop` the path is open. See
+/// `assets/paths/racetrack.path` for a full example.
+#[derive(Asset, TypePath, Clone, Debug, PartialEq)]
+pub struct SteeringPath {
+    pub points: Vec<Vec2>,
+    /// How far a follower may stray from the line before steering back.
+    pub radius: f32,
+    /// Joins the last point back to the first.
+    pub looping: bool,
+}
+
+impl SteeringPath {
+    pub fn segments(&self) -> impl Iterator<Item = (Vec2, Vec2)> + '_ {
+        let closing = match self.points.as_slice() {
+            [first, .., last] if self.looping && self.points.len() > 2 => Some((*last, *first)),
+            _ => None,
+        };
+        self.points
+            .windows(2)
+            .map(|pair| (pair[0], pair[1]))
+            .chain(closing)
+    }
+
+    /// Closest point on the path to `point`, and the direction of the
+    /// segment it lies on.
+    pub fn closest_point(&self, point: Vec2) -> Option<(Vec2, Vec2)> {
+        self.segments()
+            .map(|(start, end)| {
+                let segment = end - start;
+                let t = ((point - start).dot(segment) / segment.length_squared()).clamp(0., 1.);
+                (start + segment * t, segment.normalize_or_zero())
+            })
+            .filter(|(normal, _)| normal.is_finite())
+            .min_by(|(a, _), (b, _)| {
+                a.distance_squared(point)
+                    .total_cmp(&b.distance_squared(point))
+            })
+    }
+
+    /// Where a follower at `position` should seek to stay on the path, or
+    /// `None` while its predicted location stays within the radius.
+    pub fn follow_target(
+        &self,
+        position: Vec2,
+        velocity: Vec2,
+        lookahead: f32,
+        ahead: f32,
+    ) -> Option<Vec2> {
+        let predicted = position + velocity.normalize_or_zero() * lookahead;
+        let (normal, direction) = self.closest_point(predicted)?;
+
+        (predicted.distance(normal) > self.radius).then_some(normal + direction * ahead)
+    }
+}
+
+#[derive(Debug)]
+pub enum SteeringPathError {
+    Io(std::io::Error),
+    InvalidNumber {
+        line: usize,
+        source: ParseFloatError,
+    },
+    InvalidLine {
+        line: usize,
+    },
+    MissingRadius,
+    TooFewPoints,
+}
+
+impl fmt::Display for SteeringPathError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Io(error) => write!(f, "could not read path: {error}"),
+            Self::InvalidNumber { line, source } => write!(f, "line {line}: {source}"),
+            Self::InvalidLine { line } => {
+                write!(f, "line {line}: expected a point, `radius` or `loop`")
+            }
+            Self::MissingRadius => write!(f, "path has no `radius`"),
+            Self::TooFewPoints => write!(f, "path needs at least two points"),
+        }
+    }
+}
+
+impl std::error::Error for SteeringPathError {}
+
+impl FromStr for SteeringPath {
+    type Err = SteeringPathError;
+
+    fn from_str(source: &str) -> Result<Self, Self::Err> {
+        let mut points = Vec::new();
+        let mut radius = None;
+        let mut looping = false;
+
+        for (index, line) in source.lines().enumerate() {
+            let line_number = index + 1;
+            let number = |word: &str| {
+                word.parse::<f32>()
+                    .map_err(|source| SteeringPathError::InvalidNumber {
+                        line: line_number,
+                        source,
+                    })
+            };
+
+            let words: Vec<_> = line
+                .split('#')
+                .next()
+                .unwrap_or("")
+                .split_whitespace()
+                .collect();
+            match words.as_slice() {
+                [] => {}
+                ["loop"] => looping = true,
+                ["radius", value] => radius = Some(number(value)?),
+                [x, y] => points.push(Vec2::new(number(x)?, number(y)?)),
+                _ => return Err(SteeringPathError::InvalidLine { line: line_number }),
+            }
+        }
+
+        if points.len() < 2 {
+            return Err(SteeringPathError::TooFewPoints);
+        }
+
+        Ok(Self {
+            points,
+            radius: radius.ok_or(SteeringPathError::MissingRadius)?,
+            looping,
+        })
+    }
+}
+
+#[derive(Default)]
+pub struct SteeringPathLoader;
+
+impl AssetLoader for SteeringPathLoader {
+    type Asset = SteeringPath;
+    type Settings = ();
+    type Error = SteeringPathError;
+
+    async fn load<'a>(
+        &'a self,
+        reader: &'a mut Reader<'_>,
+        _settings: &'a (),
+        _load_context: &'a mut LoadContext<'_>,
+    ) -> Result<SteeringPath, SteeringPathError> {
+        let mut source = String::new();
+        reader
+            .read_to_string(&mut source)
+            .await
+            .map_err(SteeringPathError::Io)?;
+        source.parse()
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &["path"]
+    }
+}
+
+/// Steers a `Vehicle` along a [`SteeringPath`], seeking `ahead` of its
+/// closest point whenever the vehicle's position `lookahead` units in the
+/// future strays outside the path's radius.
+#[derive(Component, Clone)]
+pub struct PathFollower {
+    pub path: Handle<SteeringPath>,
+    pub lookahead: f32,
+    pub ahead: f32,
+    pub weight: f32,
+}
+
+impl PathFollower {
+    pub fn new(path: Handle<SteeringPath>) -> Self {
+        Self {
+            path,
+            lookahead: 5.,
+            ahead: 3.,
+            weight: 1.,
+        }
+    }
+}
+
+pub struct PathPlugin;
+
+impl Plugin for PathPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_asset::<SteeringPath>();
+        app.init_asset_loader::<SteeringPathLoader>();
+        app.add_systems(FixedUpdate, Self::follow.before(PhysicsSet));
+    }
+}
+
+impl PathPlugin {
+    fn follow(
+        paths: Res<Assets<SteeringPath>>,
+        mut followers: Query<(&PathFollower, &Vehicle, &Position, &Velocity, &mut Forces)>,
+    ) {
+        for (follower, vehicle, position, velocity, mut forces) in &mut followers {
+            let Some(path) = paths.get(&follower.path) else {
+                continue;
+            };
+
+            if let Some(target) =
+                path.follow_target(position.0, velocity.0, follower.lookahead, follower.ahead)
+            {
+                forces.apply_force(vehicle.seek(position.0, velocity.0, target) * follower.weight);
+            }
+        }
+    }
+}
@@ -0,0 +1,238 @@
+use std::ops::{Add, Mul, Sub};
+
+use bevy::prelude::*;
+
+use crate::{
+    Acceleration, AngularAcceleration, AngularVelocity, FaceVelocity, Forces, Mass,
+    MomentOfInertia, Position, PreviousAcceleration, PreviousAngularAcceleration, Rotation, Shape,
+    Torques, Velocity,
+};
+
+/// Systems that apply forces should run in `FixedUpdate` before this set.
+#[derive(SystemSet, Debug, Clone, PartialEq, Eq, Hash)]
+pub struct PhysicsSet;
+
+/// Integration scheme used by [`PhysicsPlugin`] for bodies and by
+/// [`Pendulum`](crate::Pendulum)s.
+///
+/// Bodies gather their forces once per fixed step, in the systems before
+/// [`PhysicsSet`]. `Verlet` averages that with the previous step's
+/// acceleration, kept in `PreviousAcceleration`, to finish the last step's
+/// velocity. `Rk4` can't resample forces mid-step, so for bodies it holds
+/// the acceleration constant over the step, which is exact for constant
+/// forces but not fourth order otherwise. [`Integrator::step`] with a force
+/// closure gets the full scheme.
+#[derive(Resource, Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum Integrator {
+    ExplicitEuler,
+    #[default]
+    SemiImplicitEuler,
+    Verlet,
+    Rk4,
+}
+
+impl Integrator {
+    /// Advances `position` and `velocity` by `dt`, sampling `acceleration` as a
+    /// function of position and velocity as often as the scheme needs. Works
+    /// on `Vec2` for linear motion and `f32` for angles.
+    pub fn step<T>(
+        self,
+        position: T,
+        velocity: T,
+        dt: f32,
+        acceleration: impl Fn(T, T) -> T,
+    ) -> (T, T)
+    where
+        T: Copy + Add<Output = T> + Mul<f32, Output = T>,
+    {
+        match self {
+            Self::ExplicitEuler => {
+                let a = acceleration(position, velocity);
+                (position + velocity * dt, velocity + a * dt)
+            }
+            Self::SemiImplicitEuler => {
+                let velocity = velocity + acceleration(position, velocity) * dt;
+                (position + velocity * dt, velocity)
+            }
+            Self::Verlet => {
+                let a = acceleration(position, velocity);
+                let next_position = position + velocity * dt + a * (0.5 * dt * dt);
+                let next_a = acceleration(next_position, velocity + a * dt);
+                (next_position, velocity + (a + next_a) * (0.5 * dt))
+            }
+            Self::Rk4 => {
+                let k1_x = velocity;
+                let k1_v = acceleration(position, velocity);
+
+                let k2_x = velocity + k1_v * (dt / 2.);
+                let k2_v = acceleration(position + k1_x * (dt / 2.), k2_x);
+
+                let k3_x = velocity + k2_v * (dt / 2.);
+                let k3_v = acceleration(position + k2_x * (dt / 2.), k3_x);
+
+                let k4_x = velocity + k3_v * dt;
+                let k4_v = acceleration(position + k3_x * dt, k4_x);
+
+                (
+                    position + (k1_x + k2_x * 2. + k3_x * 2. + k4_x) * (dt / 6.),
+                    velocity + (k1_v + k2_v * 2. + k3_v * 2. + k4_v) * (dt / 6.),
+                )
+            }
+        }
+    }
+
+    /// Advances a body by `dt` given the `acceleration` sampled at the start
+    /// of the step and, for `Verlet`, the `previous` step's acceleration.
+    pub fn step_sampled<T>(
+        self,
+        position: T,
+        velocity: T,
+        dt: f32,
+        acceleration: T,
+        previous: Option<T>,
+    ) -> (T, T)
+    where
+        T: Copy + Add<Output = T> + Sub<Output = T> + Mul<f32, Output = T>,
+    {
+        match (self, previous) {
+            (Self::Verlet, Some(previous)) => {
+                // The last step assumed its acceleration held until now;
+                // correct its velocity to the average of both ends.
+                let velocity = velocity + (acceleration - previous) * (0.5 * dt);
+                (
+                    position + velocity * dt + acceleration * (0.5 * dt * dt),
+                    velocity + acceleration * dt,
+                )
+            }
+            _ => self.step(position, velocity, dt, |_, _| acceleration),
+        }
+    }
+}
+
+/// Former name of the [`Integrator`] resource used for bodies.
+pub type BodyIntegrator = Integrator;
+
+pub struct PhysicsPlugin;
+
+impl Plugin for PhysicsPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<Integrator>();
+        app.add_systems(
+            FixedUpdate,
+            (
+                (Self::apply_forces, Self::apply_torques),
+                (Self::integrate, Self::integrate_rotation),
+                Self::face_velocity,
+            )
+                .chain()
+                .in_set(PhysicsSet),
+        );
+    }
+}
+
+impl PhysicsPlugin {
+    fn apply_forces(mut bodies: Query<(&mut Acceleration, &mut Forces, Option<&Mass>)>) {
+        for (mut acceleration, mut forces, mass) in &mut bodies {
+            let mass = mass.map_or(1., |mass| mass.0);
+            acceleration.0 = forces.0 / mass;
+            forces.clear();
+        }
+    }
+
+    #[allow(clippy::type_complexity)]
+    fn apply_torques(
+        mut bodies: Query<(
+            &mut AngularAcceleration,
+            &mut Torques,
+            Option<&MomentOfInertia>,
+            Option<&Mass>,
+            Option<&Shape>,
+        )>,
+    ) {
+        for (mut acceleration, mut torques, inertia, mass, shape) in &mut bodies {
+            let inertia = inertia.copied().unwrap_or_else(|| {
+                MomentOfInertia::from_shape(
+                    mass.map_or(1., |mass| mass.0),
+                    shape.map_or(Vec2::ZERO, |shape| shape.0),
+                )
+            });
+            acceleration.0 = if inertia.0 > 0. {
+                torques.0 / inertia.0
+            } else {
+                0.
+            };
+            torques.clear();
+        }
+    }
+
+    #[allow(clippy::type_complexity)]
+    fn integrate(
+        time: Res<Time>,
+        integrator: Res<Integrator>,
+        mut bodies: Query<(
+            &mut Position,
+            &mut Velocity,
+            Option<&Acceleration>,
+            Option<&mut PreviousAcceleration>,
+        )>,
+    ) {
+        let dt = time.delta_seconds();
+        for (mut position, mut velocity, acceleration, previous) in &mut bodies {
+            let acceleration = acceleration.map_or(Vec2::ZERO, |acceleration| acceleration.0);
+            let (next_position, next_velocity) = integrator.step_sampled(
+                position.0,
+                velocity.0,
+                dt,
+                acceleration,
+                previous.as_ref().and_then(|previous| previous.0),
+            );
+
+            position.0 = next_position;
+            velocity.0 = next_velocity;
+            if let Some(mut previous) = previous {
+                previous.0 = Some(acceleration);
+            }
+        }
+    }
+
+    #[allow(clippy::type_complexity)]
+    fn integrate_rotation(
+        time: Res<Time>,
+        integrator: Res<Integrator>,
+        mut bodies: Query<
+            (
+                &mut Rotation,
+                &mut AngularVelocity,
+                Option<&AngularAcceleration>,
+                Option<&mut PreviousAngularAcceleration>,
+            ),
+            Without<FaceVelocity>,
+        >,
+    ) {
+        let dt = time.delta_seconds();
+        for (mut rotation, mut velocity, acceleration, previous) in &mut bodies {
+            let acceleration = acceleration.map_or(0., |acceleration| acceleration.0);
+            let (next_rotation, next_velocity) = integrator.step_sampled(
+                rotation.0,
+                velocity.0,
+                dt,
+                acceleration,
+                previous.as_ref().and_then(|previous| previous.0),
+            );
+
+            rotation.0 = next_rotation;
+            velocity.0 = next_velocity;
+            if let Some(mut previous) = previous {
+                previous.0 = Some(acceleration);
+            }
+        }
+    }
+
+    fn face_velocity(mut bodies: Query<(&mut Rotation, &Velocity), With<FaceVelocity>>) {
+        for (mut rotation, velocity) in &mut bodies {
+            if velocity.0 != Vec2::ZERO {
+                rotation.0 = velocity.0.to_angle();
+            }
+        }
+    }
+}
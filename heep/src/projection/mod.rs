@@ -0,0 +1,113 @@
+use bevy::{ecs::query::QueryData, prelude::*, transform::TransformSystem};
+
+use crate::{Layer, Position, PreviousPosition, PreviousRotation, Rotation};
+
+/// Mutable access to a `Position` that can also jump without interpolating.
+///
+/// [`ProjectionPlugin`] draws entities between their `PreviousPosition` and
+/// `Position`, so moving an entity by writing `Position` outside the fixed
+/// step makes it streak from where it was. Use [`PlacementItem::teleport`]
+/// instead, or set `PreviousPosition` along with `Position`.
+#[derive(QueryData)]
+#[query_data(mutable)]
+pub struct Placement {
+    pub position: &'static mut Position,
+    pub previous: Option<&'static mut PreviousPosition>,
+}
+
+impl PlacementItem<'_> {
+    pub fn teleport(&mut self, position: Vec2) {
+        self.position.0 = position;
+        if let Some(previous) = &mut self.previous {
+            previous.0 = position;
+        }
+    }
+}
+
+pub struct ProjectionPlugin;
+
+impl Plugin for ProjectionPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            FixedFirst,
+            (
+                Self::track_new_positions,
+                Self::store_previous_positions,
+                Self::track_new_rotations,
+                Self::store_previous_rotations,
+            ),
+        );
+        app.add_systems(
+            PostUpdate,
+            (Self::project_positions, Self::project_rotations)
+                .chain()
+                .before(TransformSystem::TransformPropagate),
+        );
+    }
+}
+
+impl ProjectionPlugin {
+    fn track_new_positions(
+        mut commands: Commands,
+        positionables: Query<(Entity, &Position), Without<PreviousPosition>>,
+    ) {
+        for (entity, position) in &positionables {
+            commands.entity(entity).insert(PreviousPosition(position.0));
+        }
+    }
+
+    fn store_previous_positions(mut positionables: Query<(&Position, &mut PreviousPosition)>) {
+        for (position, mut previous) in &mut positionables {
+            previous.0 = position.0;
+        }
+    }
+
+    fn track_new_rotations(
+        mut commands: Commands,
+        rotatables: Query<(Entity, &Rotation), Without<PreviousRotation>>,
+    ) {
+        for (entity, rotation) in &rotatables {
+            commands.entity(entity).insert(PreviousRotation(rotation.0));
+        }
+    }
+
+    fn store_previous_rotations(mut rotatables: Query<(&Rotation, &mut PreviousRotation)>) {
+        for (rotation, mut previous) in &mut rotatables {
+            previous.0 = rotation.0;
+        }
+    }
+
+    fn project_positions(
+        time: Res<Time<Fixed>>,
+        mut positionables: Query<(
+            &mut Transform,
+            &Position,
+            Option<&PreviousPosition>,
+            Option<&Layer>,
+        )>,
+    ) {
+        let alpha = time.overstep_fraction();
+        for (mut transform, position, previous, layer) in &mut positionables {
+            let translation = match previous {
+                Some(previous) => previous.0.lerp(position.0, alpha),
+                None => position.0,
+            };
+            let z = layer.map_or(0., |layer| layer.0);
+            transform.translation = translation.extend(z);
+        }
+    }
+
+    fn project_rotations(
+        time: Res<Time<Fixed>>,
+        mut rotatables: Query<(&mut Transform, &Rotation, Option<&PreviousRotation>)>,
+    ) {
+        let alpha = time.overstep_fraction();
+        for (mut transform, rotation, previous) in &mut rotatables {
+            let current = Quat::from_rotation_z(rotation.0);
+            transform.rotation = match previous {
+                Some(previous) => Quat::from_rotation_z(previous.0).slerp(current, alpha),
+                None => current,
+            };
+        }
+    }
+}
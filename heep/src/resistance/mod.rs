@@ -0,0 +1,115 @@
+use bevy::prelude::*;
+
+use crate::{Forces, Mass, PhysicsSet, Position, Shape, Velocity};
+
+/// Kinetic friction opposing a body's motion with magnitude `coefficient * normal`.
+#[derive(Component, Clone, Copy)]
+pub struct Friction {
+    pub coefficient: f32,
+    pub normal: f32,
+}
+
+impl Default for Friction {
+    fn default() -> Self {
+        Self {
+            coefficient: 0.1,
+            normal: 1.,
+        }
+    }
+}
+
+/// Quadratic drag, `½ρv²CdA`, felt by a body moving through a fluid. `A` is
+/// the width of the body's `Shape` seen from its direction of travel.
+#[derive(Component, Clone, Copy)]
+pub struct Drag {
+    /// Drag coefficient, `Cd`.
+    pub coefficient: f32,
+}
+
+impl Default for Drag {
+    fn default() -> Self {
+        Self { coefficient: 0.5 }
+    }
+}
+
+/// Density of the fluid everywhere outside a [`FluidRegion`].
+#[derive(Resource, Default, Clone, Copy)]
+pub struct AmbientDensity(pub f32);
+
+/// An axis-aligned area of fluid spanning the region entity's `Position` and
+/// `Shape` half extents. Overlapping regions add their densities.
+#[derive(Component, Clone, Copy)]
+pub struct FluidRegion {
+    pub density: f32,
+}
+
+pub struct ResistancePlugin;
+
+impl Plugin for ResistancePlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<AmbientDensity>();
+        app.add_systems(
+            FixedUpdate,
+            (Self::apply_friction, Self::apply_drag).before(PhysicsSet),
+        );
+    }
+}
+
+impl ResistancePlugin {
+    /// Largest force that stops a body within a step without reversing it.
+    fn stopping_force(velocity: Vec2, mass: Option<&Mass>, dt: f32) -> f32 {
+        velocity.length() * mass.map_or(1., |mass| mass.0) / dt
+    }
+
+    fn apply_friction(
+        time: Res<Time>,
+        mut bodies: Query<(&Friction, &Velocity, Option<&Mass>, &mut Forces)>,
+    ) {
+        let dt = time.delta_seconds();
+        for (friction, velocity, mass, mut forces) in &mut bodies {
+            let magnitude = (friction.coefficient * friction.normal)
+                .min(Self::stopping_force(velocity.0, mass, dt));
+            forces.apply_force(-velocity.0.normalize_or_zero() * magnitude);
+        }
+    }
+
+    #[allow(clippy::type_complexity)]
+    fn apply_drag(
+        time: Res<Time>,
+        ambient: Res<AmbientDensity>,
+        regions: Query<(&FluidRegion, &Position, &Shape)>,
+        mut bodies: Query<(
+            &Drag,
+            &Position,
+            &Velocity,
+            Option<&Shape>,
+            Option<&Mass>,
+            &mut Forces,
+        )>,
+    ) {
+        let dt = time.delta_seconds();
+        for (drag, position, velocity, shape, mass, mut forces) in &mut bodies {
+            let density = ambient.0
+                + regions
+                    .iter()
+                    .filter(|(_, region_position, region_shape)| {
+                        Rect::from_center_half_size(region_position.0, region_shape.0)
+                            .contains(position.0)
+                    })
+                    .map(|(region, _, _)| region.density)
+                    .sum::<f32>();
+
+            let direction = velocity.0.normalize_or_zero();
+            if density <= 0. || direction == Vec2::ZERO {
+                continue;
+            }
+
+            let area = shape.map_or(1., |shape| {
+                2. * (shape.0.x * direction.y.abs() + shape.0.y * direction.x.abs())
+            });
+            let magnitude = (0.5 * density * velocity.0.length_squared() * drag.coefficient * area)
+                .min(Self::stopping_force(velocity.0, mass, dt));
+            forces.apply_force(-direction * magnitude);
+        }
+    }
+}
@@ -0,0 +1,93 @@
+use bevy::{dev_tools::fps_overlay::FpsOverlayPlugin, prelude::*, render::camera::ScalingMode};
+use bevy_turborand::{prelude::RngPlugin, DelegatedRng, GlobalRng};
+
+use crate::{BoundsPlugin, Perlin, PhysicsPlugin, ProjectionPlugin};
+
+/// Default window, camera, rng and noise setup, plus physics, projection and
+/// bounds, shared by every sketch. Sketches add the other heep plugins they
+/// use themselves.
+///
+/// ```no_run
+/// use bevy::prelude::*;
+/// use heep::{ResistancePlugin, Sketch};
+///
+/// App::new()
+///     .add_plugins((Sketch::new("heep - Example"), ResistancePlugin))
+///     .run();
+/// ```
+pub struct Sketch {
+    title: String,
+    resolution: Vec2,
+    background: Color,
+    world_height: f32,
+    seed: Option<u64>,
+}
+
+impl Sketch {
+    pub fn new(title: impl Into<String>) -> Self {
+        Self {
+            title: title.into(),
+            resolution: Vec2::new(1280., 720.),
+            background: Color::srgb(0.2, 0.4, 0.6),
+            world_height: 100.,
+            seed: None,
+        }
+    }
+
+    pub fn with_resolution(mut self, width: f32, height: f32) -> Self {
+        self.resolution = Vec2::new(width, height);
+        self
+    }
+
+    pub fn with_background(mut self, background: Color) -> Self {
+        self.background = background;
+        self
+    }
+
+    /// Height of the visible world in world units, regardless of window size.
+    pub fn with_world_height(mut self, world_height: f32) -> Self {
+        self.world_height = world_height;
+        self
+    }
+
+    pub fn with_seed(mut self, seed: u64) -> Self {
+        self.seed = Some(seed);
+        self
+    }
+}
+
+impl Plugin for Sketch {
+    fn build(&self, app: &mut App) {
+        let rng = match self.seed {
+            Some(seed) => RngPlugin::new().with_rng_seed(seed),
+            None => RngPlugin::new(),
+        };
+
+        app.insert_resource(ClearColor(self.background));
+        app.add_plugins((
+            DefaultPlugins.set(WindowPlugin {
+                primary_window: Some(Window {
+                    title: self.title.clone(),
+                    resolution: self.resolution.into(),
+                    ..Default::default()
+                }),
+                ..Default::default()
+            }),
+            FpsOverlayPlugin::default(),
+            rng,
+            PhysicsPlugin,
+            ProjectionPlugin,
+            BoundsPlugin,
+        ));
+
+        let noise_seed = app.world_mut().resource_mut::<GlobalRng>().u64(..);
+        app.insert_resource(Perlin::new(noise_seed));
+
+        let world_height = self.world_height;
+        app.add_systems(Startup, move |mut commands: Commands| {
+            let mut camera = Camera2dBundle::default();
+            camera.projection.scaling_mode = ScalingMode::FixedVertical(world_height);
+            commands.spawn(camera);
+        });
+    }
+}
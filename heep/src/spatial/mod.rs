@@ -0,0 +1,195 @@
+use std::collections::HashMap;
+
+use bevy::prelude::*;
+
+use crate::{Position, Shape};
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct SpatialEntry {
+    pub entity: Entity,
+    pub position: Vec2,
+    /// Half extents taken from the entity's `Shape`, zero without one.
+    pub extents: Vec2,
+}
+
+impl SpatialEntry {
+    pub fn bounds(&self) -> Rect {
+        Rect::from_center_half_size(self.position, self.extents)
+    }
+}
+
+/// Bin-lattice of every entity with a `Position`, rebuilt at the start of
+/// each fixed step. Entities are binned into every cell their `Shape`
+/// overlaps, and queries only visit the cells they cover.
+#[derive(Resource)]
+pub struct SpatialIndex {
+    cell_size: f32,
+    cells: HashMap<IVec2, Vec<usize>>,
+    entries: Vec<SpatialEntry>,
+}
+
+impl Default for SpatialIndex {
+    fn default() -> Self {
+        Self::new(10.)
+    }
+}
+
+impl SpatialIndex {
+    pub fn new(cell_size: f32) -> Self {
+        Self {
+            cell_size,
+            cells: HashMap::new(),
+            entries: Vec::new(),
+        }
+    }
+
+    pub fn cell_size(&self) -> f32 {
+        self.cell_size
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    pub fn clear(&mut self) {
+        // Keep the allocations of cells used last time around for the next
+        // rebuild, and drop the rest so the map doesn't grow without bound.
+        self.cells.retain(|_, cell| {
+            let used = !cell.is_empty();
+            cell.clear();
+            used
+        });
+        self.entries.clear();
+    }
+
+    fn cell(&self, point: Vec2) -> IVec2 {
+        (point / self.cell_size).floor().as_ivec2()
+    }
+
+    pub fn insert(&mut self, entity: Entity, position: Vec2, extents: Vec2) {
+        let entry = SpatialEntry {
+            entity,
+            position,
+            extents,
+        };
+        let index = self.entries.len();
+        let (min, max) = (self.cell(entry.bounds().min), self.cell(entry.bounds().max));
+
+        for y in min.y..=max.y {
+            for x in min.x..=max.x {
+                self.cells.entry(IVec2::new(x, y)).or_default().push(index);
+            }
+        }
+        self.entries.push(entry);
+    }
+
+    /// Calls `f` once for every entry whose bounds overlap `rect`.
+    pub fn for_each_in_aabb(&self, rect: Rect, mut f: impl FnMut(&SpatialEntry)) {
+        let (min, max) = (self.cell(rect.min), self.cell(rect.max));
+
+        for y in min.y..=max.y {
+            for x in min.x..=max.x {
+                let Some(cell) = self.cells.get(&IVec2::new(x, y)) else {
+                    continue;
+                };
+
+                for &index in cell {
+                    let entry = &self.entries[index];
+                    let bounds = entry.bounds();
+                    // An entry spanning several visited cells is only
+                    // reported from the first of them.
+                    let home = self.cell(bounds.min).max(min);
+                    if home == IVec2::new(x, y) && overlaps(bounds, rect) {
+                        f(entry);
+                    }
+                }
+            }
+        }
+    }
+
+    pub fn query_aabb(&self, rect: Rect) -> Vec<SpatialEntry> {
+        let mut entries = Vec::new();
+        self.for_each_in_aabb(rect, |entry| entries.push(*entry));
+        entries
+    }
+
+    /// Calls `f` once for every entry whose bounds come within `radius` of `center`.
+    pub fn for_each_in_radius(&self, center: Vec2, radius: f32, mut f: impl FnMut(&SpatialEntry)) {
+        let rect = Rect::from_center_half_size(center, Vec2::splat(radius));
+        self.for_each_in_aabb(rect, |entry| {
+            let closest = center.clamp(entry.bounds().min, entry.bounds().max);
+            if closest.distance_squared(center) <= radius * radius {
+                f(entry);
+            }
+        });
+    }
+
+    pub fn query_radius(&self, center: Vec2, radius: f32) -> Vec<SpatialEntry> {
+        let mut entries = Vec::new();
+        self.for_each_in_radius(center, radius, |entry| entries.push(*entry));
+        entries
+    }
+
+    /// The `k` entries whose positions are nearest to `point`, closest first.
+    pub fn nearest(&self, point: Vec2, k: usize) -> Vec<SpatialEntry> {
+        if k == 0 || self.entries.is_empty() {
+            return Vec::new();
+        }
+
+        // Grow a square search area ring by ring until it holds k entries
+        // and the kth is closer than anything outside the area could be.
+        let mut radius = self.cell_size;
+        loop {
+            let mut found =
+                self.query_aabb(Rect::from_center_half_size(point, Vec2::splat(radius)));
+            found.sort_by(|a, b| {
+                a.position
+                    .distance_squared(point)
+                    .total_cmp(&b.position.distance_squared(point))
+            });
+
+            let everything = found.len() == self.entries.len();
+            let settled = found.len() >= k && found[k - 1].position.distance(point) <= radius;
+            if everything || settled {
+                found.truncate(k);
+                return found;
+            }
+
+            radius *= 2.;
+        }
+    }
+}
+
+/// Like `Rect::intersect` but counts touching edges and zero-sized rects.
+fn overlaps(a: Rect, b: Rect) -> bool {
+    a.min.x <= b.max.x && b.min.x <= a.max.x && a.min.y <= b.max.y && b.min.y <= a.max.y
+}
+
+pub struct SpatialIndexPlugin;
+
+impl Plugin for SpatialIndexPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<SpatialIndex>();
+        app.add_systems(FixedPreUpdate, Self::rebuild);
+    }
+}
+
+impl SpatialIndexPlugin {
+    fn rebuild(
+        mut index: ResMut<SpatialIndex>,
+        entities: Query<(Entity, &Position, Option<&Shape>)>,
+    ) {
+        index.clear();
+        for (entity, position, shape) in &entities {
+            index.insert(
+                entity,
+                position.0,
+                shape.map_or(Vec2::ZERO, |shape| shape.0),
+            );
+        }
+    }
+}
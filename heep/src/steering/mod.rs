@@ -0,0 +1,254 @@
+use bevy::prelude::*;
+use bevy_turborand::{DelegatedRng, GlobalRng};
+
+use crate::{Forces, PhysicsSet, Position, Velocity};
+
+/// An autonomous agent that steers itself with a limited force, following
+/// Craig Reynolds' steering behaviors.
+#[derive(Component, Clone, Copy)]
+pub struct Vehicle {
+    pub max_speed: f32,
+    pub max_force: f32,
+}
+
+impl Vehicle {
+    pub fn new(max_speed: f32, max_force: f32) -> Self {
+        Self {
+            max_speed,
+            max_force,
+        }
+    }
+
+    /// Steering force toward `desired` velocity: desired minus current,
+    /// limited to `max_force`.
+    pub fn steer(&self, velocity: Vec2, desired: Vec2) -> Vec2 {
+        (desired - velocity).clamp_length_max(self.max_force)
+    }
+
+    pub fn seek(&self, position: Vec2, velocity: Vec2, target: Vec2) -> Vec2 {
+        let desired = (target - position).normalize_or_zero() * self.max_speed;
+        self.steer(velocity, desired)
+    }
+
+    /// Steers away from `target` while it is within `radius`.
+    pub fn flee(&self, position: Vec2, velocity: Vec2, target: Vec2, radius: f32) -> Vec2 {
+        if position.distance(target) > radius {
+            return Vec2::ZERO;
+        }
+
+        let desired = (position - target).normalize_or_zero() * self.max_speed;
+        self.steer(velocity, desired)
+    }
+
+    /// Seeks `target`, slowing linearly to a stop inside `slowing_radius`.
+    pub fn arrive(
+        &self,
+        position: Vec2,
+        velocity: Vec2,
+        target: Vec2,
+        slowing_radius: f32,
+    ) -> Vec2 {
+        let offset = target - position;
+        let distance = offset.length();
+        let speed = if distance < slowing_radius {
+            self.max_speed * distance / slowing_radius
+        } else {
+            self.max_speed
+        };
+
+        self.steer(velocity, offset.normalize_or_zero() * speed)
+    }
+
+    /// Where a target moving at `target_velocity` will be by the time we
+    /// could reach it.
+    fn predict(&self, position: Vec2, target: Vec2, target_velocity: Vec2) -> Vec2 {
+        let time = if self.max_speed > 0. {
+            position.distance(target) / self.max_speed
+        } else {
+            0.
+        };
+        target + target_velocity * time
+    }
+
+    pub fn pursue(
+        &self,
+        position: Vec2,
+        velocity: Vec2,
+        target: Vec2,
+        target_velocity: Vec2,
+    ) -> Vec2 {
+        self.seek(
+            position,
+            velocity,
+            self.predict(position, target, target_velocity),
+        )
+    }
+
+    pub fn evade(
+        &self,
+        position: Vec2,
+        velocity: Vec2,
+        target: Vec2,
+        target_velocity: Vec2,
+        radius: f32,
+    ) -> Vec2 {
+        let predicted = self.predict(position, target, target_velocity);
+        self.flee(position, velocity, predicted, radius)
+    }
+}
+
+/// Wandering state: a point on a circle projected ahead of the vehicle that
+/// drifts randomly around the circle each step.
+#[derive(Clone, Copy)]
+pub struct Wander {
+    /// How far ahead of the vehicle the circle sits.
+    pub distance: f32,
+    pub radius: f32,
+    /// Largest change to `angle` per step, in radians.
+    pub jitter: f32,
+    /// Angle of the target on the circle, relative to the heading.
+    pub angle: f32,
+}
+
+impl Default for Wander {
+    fn default() -> Self {
+        Self {
+            distance: 8.,
+            radius: 4.,
+            jitter: 0.3,
+            angle: 0.,
+        }
+    }
+}
+
+impl Wander {
+    pub fn target(&self, position: Vec2, velocity: Vec2) -> Vec2 {
+        let heading = velocity.try_normalize().unwrap_or(Vec2::X);
+        let center = position + heading * self.distance;
+        center + Vec2::from_angle(self.angle).rotate(heading) * self.radius
+    }
+}
+
+/// Something a behavior steers relative to.
+#[derive(Clone, Copy)]
+pub enum SteeringTarget {
+    Point(Vec2),
+    /// An entity with a `Position` and optionally a `Velocity`.
+    Entity(Entity),
+}
+
+#[derive(Clone, Copy)]
+pub enum Behavior {
+    Seek(SteeringTarget),
+    Flee {
+        target: SteeringTarget,
+        radius: f32,
+    },
+    Arrive {
+        target: SteeringTarget,
+        slowing_radius: f32,
+    },
+    Pursue(SteeringTarget),
+    Evade {
+        target: SteeringTarget,
+        radius: f32,
+    },
+    Wander(Wander),
+}
+
+/// Weighted behaviors whose forces are summed into a vehicle's `Forces`
+/// each step. The total is limited to the vehicle's `max_force`.
+#[derive(Component, Clone, Default)]
+pub struct Steering {
+    pub behaviors: Vec<(Behavior, f32)>,
+}
+
+impl Steering {
+    pub fn with(mut self, behavior: Behavior, weight: f32) -> Self {
+        self.behaviors.push((behavior, weight));
+        self
+    }
+}
+
+pub struct SteeringPlugin;
+
+impl Plugin for SteeringPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            FixedUpdate,
+            (
+                Self::steer.before(PhysicsSet),
+                Self::limit_speed.after(PhysicsSet),
+            ),
+        );
+    }
+}
+
+impl SteeringPlugin {
+    fn resolve(
+        target: SteeringTarget,
+        targets: &Query<(&Position, Option<&Velocity>)>,
+    ) -> Option<(Vec2, Vec2)> {
+        match target {
+            SteeringTarget::Point(point) => Some((point, Vec2::ZERO)),
+            SteeringTarget::Entity(entity) => {
+                targets.get(entity).ok().map(|(position, velocity)| {
+                    (
+                        position.0,
+                        velocity.map_or(Vec2::ZERO, |velocity| velocity.0),
+                    )
+                })
+            }
+        }
+    }
+
+    fn steer(
+        mut rng: ResMut<GlobalRng>,
+        targets: Query<(&Position, Option<&Velocity>)>,
+        mut vehicles: Query<(&Vehicle, &mut Steering, &Position, &Velocity, &mut Forces)>,
+    ) {
+        for (vehicle, mut steering, position, velocity, mut forces) in &mut vehicles {
+            let (position, velocity) = (position.0, velocity.0);
+            let mut total = Vec2::ZERO;
+
+            for (behavior, weight) in &mut steering.behaviors {
+                let force = match behavior {
+                    Behavior::Seek(target) => Self::resolve(*target, &targets)
+                        .map(|(target, _)| vehicle.seek(position, velocity, target)),
+                    Behavior::Flee { target, radius } => Self::resolve(*target, &targets)
+                        .map(|(target, _)| vehicle.flee(position, velocity, target, *radius)),
+                    Behavior::Arrive {
+                        target,
+                        slowing_radius,
+                    } => Self::resolve(*target, &targets).map(|(target, _)| {
+                        vehicle.arrive(position, velocity, target, *slowing_radius)
+                    }),
+                    Behavior::Pursue(target) => {
+                        Self::resolve(*target, &targets).map(|(target, target_velocity)| {
+                            vehicle.pursue(position, velocity, target, target_velocity)
+                        })
+                    }
+                    Behavior::Evade { target, radius } => {
+                        Self::resolve(*target, &targets).map(|(target, target_velocity)| {
+                            vehicle.evade(position, velocity, target, target_velocity, *radius)
+                        })
+                    }
+                    Behavior::Wander(wander) => {
+                        wander.angle += (rng.f32() * 2. - 1.) * wander.jitter;
+                        Some(vehicle.seek(position, velocity, wander.target(position, velocity)))
+                    }
+                };
+
+                total += force.unwrap_or_default() * *weight;
+            }
+
+            forces.apply_force(total.clamp_length_max(vehicle.max_force));
+        }
+    }
+
+    fn limit_speed(mut vehicles: Query<(&Vehicle, &mut Velocity)>) {
+        for (vehicle, mut velocity) in &mut vehicles {
+            velocity.0 = velocity.0.clamp_length_max(vehicle.max_speed);
+        }
+    }
+}
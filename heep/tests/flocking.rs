@@ -0,0 +1,57 @@
+use std::time::Duration;
+
+use bevy::{
+    prelude::*,
+    time::{TimePlugin, TimeUpdateStrategy},
+};
+use heep::{
+    Boid, EdgeBehavior, Flock, FlockingPlugin, Forces, Position, Vehicle, Velocity, WorldBounds,
+};
+
+/// Two boids either side of the vertical seam of a 100 unit wide world,
+/// 4 units apart through the wrap. Returns the forces on each after a step.
+fn forces_across_seam(edge: EdgeBehavior) -> (Vec2, Vec2) {
+    let step = Duration::from_secs_f32(1. / 60.);
+    let mut app = App::new();
+    app.add_plugins((TimePlugin, FlockingPlugin))
+        .insert_resource(WorldBounds(Rect::new(-50., -50., 50., 50.)))
+        .insert_resource(Time::<Fixed>::from_duration(step))
+        .insert_resource(TimeUpdateStrategy::ManualDuration(step));
+
+    let flock = app.world_mut().spawn(Flock::default()).id();
+    let mut boid = |x: f32| {
+        app.world_mut()
+            .spawn((
+                Boid { flock },
+                Vehicle::new(10., 1.),
+                Position(Vec2::new(x, 0.)),
+                Velocity(Vec2::new(0., 5.)),
+                Forces::default(),
+                edge,
+            ))
+            .id()
+    };
+    let (east, west) = (boid(48.), boid(-48.));
+
+    // The first update only starts the clock, the second runs a fixed step.
+    app.update();
+    app.update();
+    let force = |entity| app.world().get::<Forces>(entity).unwrap().0;
+    (force(east), force(west))
+}
+
+#[test]
+fn wrapping_boids_see_neighbors_across_the_seam() {
+    let (east, west) = forces_across_seam(EdgeBehavior::Wrap);
+
+    assert!(east.x > 0., "east boid pulled by {east}");
+    assert!(west.x < 0., "west boid pulled by {west}");
+}
+
+#[test]
+fn bouncing_boids_ignore_neighbors_across_the_seam() {
+    let (east, west) = forces_across_seam(EdgeBehavior::Bounce { restitution: 1. });
+
+    assert_eq!(east, Vec2::ZERO);
+    assert_eq!(west, Vec2::ZERO);
+}
@@ -0,0 +1,165 @@
+use bevy_turborand::{rng::Rng, SeededCore, TurboRand};
+use heep::{Genome, Population, Selection};
+
+const TARGET: &str = "to be or not to be";
+const CHARSET: &[u8] = b"abcdefghijklmnopqrstuvwxyz ";
+
+/// A phrase typed by a monkey, one random character per gene.
+#[derive(Clone, Debug)]
+struct Phrase(Vec<u8>);
+
+impl Phrase {
+    fn letter(rng: &impl TurboRand) -> u8 {
+        CHARSET[rng.usize(..CHARSET.len())]
+    }
+
+    /// Fraction of matching characters, squared to reward each extra match
+    /// more than the last.
+    fn fitness(&self) -> f32 {
+        let matches = self
+            .0
+            .iter()
+            .zip(TARGET.bytes())
+            .filter(|(a, b)| **a == *b)
+            .count();
+        (matches as f32 / TARGET.len() as f32).powi(2)
+    }
+}
+
+impl Genome for Phrase {
+    type Config = usize;
+
+    fn random(length: &usize, rng: &impl TurboRand) -> Self {
+        Self((0..*length).map(|_| Self::letter(rng)).collect())
+    }
+
+    fn crossover(&self, other: &Self, rng: &impl TurboRand) -> Self {
+        let midpoint = rng.usize(..self.0.len());
+        Self([&self.0[..midpoint], &other.0[midpoint..]].concat())
+    }
+
+    fn mutate(&mut self, rate: f32, rng: &impl TurboRand) {
+        for gene in &mut self.0 {
+            if rng.f32() < rate {
+                *gene = Self::letter(rng);
+            }
+        }
+    }
+}
+
+fn evolve_monkeys(selection: Selection, elitism: usize, seed: u64) -> Population<Phrase> {
+    let rng = Rng::with_seed(seed);
+    let mut population = Population::<Phrase>::random(300, &TARGET.len(), &rng)
+        .with_selection(selection)
+        .with_elitism(elitism)
+        .with_mutation_rate(0.01);
+
+    for _ in 0..2000 {
+        if population.evaluate(Phrase::fitness).best == 1. {
+            break;
+        }
+        population.evolve(&rng);
+    }
+    population
+}
+
+#[test]
+fn monkeys_type_the_phrase() {
+    for (selection, elitism) in [
+        (Selection::Roulette, 0),
+        (Selection::Tournament { size: 4 }, 2),
+        (Selection::Rank, 2),
+    ] {
+        let population = evolve_monkeys(selection, elitism, 42);
+        let (best, fitness) = population.best().unwrap();
+
+        assert_eq!(fitness, 1., "{selection:?} did not converge");
+        assert_eq!(String::from_utf8(best.0.clone()).unwrap(), TARGET);
+    }
+}
+
+#[test]
+fn statistics_are_recorded_per_generation() {
+    let population = evolve_monkeys(Selection::Tournament { size: 4 }, 2, 7);
+    let history = &population.history;
+
+    assert_eq!(history.len(), population.generation + 1);
+    assert!(history
+        .iter()
+        .all(|s| s.worst <= s.mean && s.mean <= s.best));
+    assert!(history.last().unwrap().mean > history[0].mean);
+    // Elites carry over, so the best fitness never drops.
+    assert!(history.windows(2).all(|pair| pair[1].best >= pair[0].best));
+}
+
+#[test]
+fn elitism_keeps_the_fittest_unchanged() {
+    let rng = Rng::with_seed(3);
+    let mut population = Population::<Phrase>::random(20, &TARGET.len(), &rng)
+        .with_elitism(3)
+        .with_mutation_rate(1.);
+    population.evaluate(Phrase::fitness);
+    let elite: Vec<_> = population
+        .ranked()
+        .into_iter()
+        .take(3)
+        .map(|index| population.members[index].0.clone())
+        .collect();
+
+    population.evolve(&rng);
+
+    let kept: Vec<_> = population.members[..3]
+        .iter()
+        .map(|m| m.0.clone())
+        .collect();
+    assert_eq!(kept, elite);
+    assert_eq!(population.generation, 1);
+}
+
+#[test]
+fn selection_favours_the_fit() {
+    let rng = Rng::with_seed(11);
+    let mut population = Population::new((0..4).map(|i| Phrase(vec![i])).collect());
+    population.set_fitness(vec![0., 1., 2., 7.]);
+
+    for (selection, expected) in [
+        (Selection::Roulette, [0., 0.1, 0.2, 0.7]),
+        (Selection::Rank, [0.1, 0.2, 0.3, 0.4]),
+    ] {
+        population.selection = selection;
+        let mut counts = [0f32; 4];
+        for _ in 0..10_000 {
+            counts[population.select(&rng).unwrap()] += 1. / 10_000.;
+        }
+        for (count, expected) in counts.iter().zip(expected) {
+            assert!((count - expected).abs() < 0.02, "{selection:?}: {counts:?}");
+        }
+    }
+
+    population.selection = Selection::Tournament { size: 4 };
+    let wins = (0..1000)
+        .filter(|_| population.select(&rng) == Some(3))
+        .count();
+    // The fittest wins whenever it is drawn at least once.
+    assert!((wins as f32 / 1000. - (1. - 0.75f32.powi(4))).abs() < 0.05);
+}
+
+#[test]
+fn empty_population_selects_nothing() {
+    let rng = Rng::with_seed(3);
+    let mut population = Population::<Phrase>::new(Vec::new()).with_elitism(2);
+    population.set_fitness(Vec::new());
+
+    for selection in [
+        Selection::Roulette,
+        Selection::Tournament { size: 4 },
+        Selection::Rank,
+    ] {
+        population.selection = selection;
+        assert_eq!(population.select(&rng), None);
+    }
+
+    population.evolve(&rng);
+    assert!(population.members.is_empty());
+    assert_eq!(population.generation, 1);
+}
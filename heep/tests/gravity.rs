@@ -0,0 +1,95 @@
+use bevy::prelude::*;
+use bevy_turborand::{rng::Rng, SeededCore, TurboRand};
+use heep::{Gravitation, GravitySolver};
+
+fn bodies(count: usize) -> Vec<(Vec2, f32)> {
+    let rng = Rng::with_seed(7);
+    (0..count)
+        .map(|_| {
+            let position = Vec2::new(rng.f32_normalized(), rng.f32_normalized()) * 500.;
+            let mass = 1. + rng.f32() * 9.;
+            (position, mass)
+        })
+        .collect()
+}
+
+fn relative_error(expected: &[Vec2], actual: &[Vec2]) -> f32 {
+    let error: f32 = expected
+        .iter()
+        .zip(actual)
+        .map(|(expected, actual)| expected.distance(*actual))
+        .sum();
+    let magnitude: f32 = expected.iter().map(|force| force.length()).sum();
+    error / magnitude
+}
+
+fn gravitation(solver: GravitySolver) -> Gravitation {
+    Gravitation {
+        solver,
+        ..Default::default()
+    }
+}
+
+#[test]
+fn barnes_hut_matches_exact_within_tolerance() {
+    let bodies = bodies(2000);
+    let exact = gravitation(GravitySolver::Exact).forces(&bodies);
+    let approximate = gravitation(GravitySolver::BarnesHut { theta: 0.5 }).forces(&bodies);
+
+    let error = relative_error(&exact, &approximate);
+    assert!(error < 0.01, "relative error {error}");
+}
+
+#[test]
+fn barnes_hut_with_zero_theta_is_exact() {
+    let bodies = bodies(300);
+    let exact = gravitation(GravitySolver::Exact).forces(&bodies);
+    let approximate = gravitation(GravitySolver::BarnesHut { theta: 0. }).forces(&bodies);
+
+    let error = relative_error(&exact, &approximate);
+    assert!(error < 1e-4, "relative error {error}");
+}
+
+#[test]
+fn attraction_is_clamped_near_zero_distance() {
+    let gravitation = Gravitation::default();
+    let near = gravitation.attraction(Vec2::ZERO, 1., Vec2::new(0.01, 0.), 1.);
+    let at_min =
+        gravitation.attraction(Vec2::ZERO, 1., Vec2::new(gravitation.min_distance, 0.), 1.);
+
+    assert!(near.is_finite());
+    assert!((near - at_min).length() < 1e-6);
+}
+
+#[test]
+fn bodies_sharing_a_leaf_do_not_attract_themselves() {
+    // Closer together than the smallest cell, so they merge into one leaf.
+    let bodies = [
+        (Vec2::ZERO, 1.),
+        (Vec2::new(1e-6, 0.), 1.),
+        (Vec2::new(0., 1e-6), 3.),
+        (Vec2::new(1000., 0.), 1.),
+    ];
+    let exact = gravitation(GravitySolver::Exact).forces(&bodies);
+    let approximate = gravitation(GravitySolver::BarnesHut { theta: 0.5 }).forces(&bodies);
+
+    let error = relative_error(&exact, &approximate);
+    assert!(error < 1e-4, "relative error {error}");
+}
+
+#[test]
+fn large_theta_never_approximates_a_node_around_the_body() {
+    let barnes_hut = gravitation(GravitySolver::BarnesHut { theta: 1.5 });
+
+    let lone = barnes_hut.forces(&[(Vec2::new(3., -2.), 5.)]);
+    assert_eq!(lone, [Vec2::ZERO]);
+
+    // From the light body, the whole tree looks far enough away to be
+    // summarised by its center of mass, which includes the body itself.
+    let bodies = [(Vec2::ZERO, 1.), (Vec2::new(10., 0.), 100.)];
+    let exact = gravitation(GravitySolver::Exact).forces(&bodies);
+    let approximate = barnes_hut.forces(&bodies);
+
+    let error = relative_error(&exact, &approximate);
+    assert!(error < 1e-5, "relative error {error}");
+}
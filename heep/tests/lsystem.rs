@@ -0,0 +1,106 @@
+use bevy::prelude::*;
+use bevy_turborand::{rng::Rng, SeededCore};
+use heep::{line_mesh, LSystem, Turtle};
+
+fn koch() -> LSystem {
+    LSystem::new("F").with_rule('F', "F+F-F-F+F")
+}
+
+fn fractal_plant() -> LSystem {
+    LSystem::new("X")
+        .with_rule('X', "F+[[X]-X]-X[-X]+X")
+        .with_rule('F', "FF")
+}
+
+#[test]
+fn expands_koch_curve() {
+    let rng = Rng::with_seed(0);
+
+    assert_eq!(koch().expand(0, &rng), "F");
+    assert_eq!(koch().expand(1, &rng), "F+F-F-F+F");
+    assert_eq!(
+        koch().expand(2, &rng),
+        "F+F-F-F+F+F+F-F-F+F-F+F-F-F+F-F+F-F-F+F+F+F-F-F+F"
+    );
+}
+
+#[test]
+fn expands_fractal_plant() {
+    let rng = Rng::with_seed(0);
+
+    assert_eq!(fractal_plant().expand(1, &rng), "F+[[X]-X]-X[-X]+X");
+    assert_eq!(
+        fractal_plant().expand(2, &rng),
+        "FF+[[F+[[X]-X]-X[-X]+X]-F+[[X]-X]-X[-X]+X]-F+[[X]-X]-X[-X]+X[-F+[[X]-X]-X[-X]+X]+F+[[X]-X]-X[-X]+X"
+    );
+}
+
+#[test]
+fn koch_curve_segment_count() {
+    let rng = Rng::with_seed(0);
+    let turtle = Turtle::new(1., 90_f32.to_radians());
+
+    for generations in 0..5 {
+        let segments = turtle.interpret(&koch().expand(generations, &rng));
+        assert_eq!(segments.len(), 5usize.pow(generations as u32));
+    }
+
+    // Each generation triples the span of the curve.
+    let segments = turtle.interpret(&koch().expand(3, &rng));
+    let end = segments.last().unwrap().end;
+    assert!((end - Vec2::new(27., 0.)).length() < 1e-3);
+}
+
+#[test]
+fn fractal_plant_segment_count() {
+    let rng = Rng::with_seed(0);
+    let turtle = Turtle::new(1., 25_f32.to_radians());
+
+    // Each X becomes five X and one F, and each F doubles.
+    let (mut x, mut f) = (1, 0);
+    for generations in 1..=5 {
+        (x, f) = (x * 5, f * 2 + x);
+        let segments = turtle.interpret(&fractal_plant().expand(generations, &rng));
+        assert_eq!(segments.len(), f);
+    }
+}
+
+#[test]
+fn push_and_pop_restore_state() {
+    let turtle = Turtle::new(2., 90_f32.to_radians()).with_scales(0.5, 1.);
+    let segments = turtle.interpret("F[+>F]F");
+
+    assert_eq!(segments.len(), 3);
+    assert!((segments[1].end - Vec2::new(2., 1.)).length() < 1e-5);
+    assert_eq!(segments[1].depth, 1);
+    assert!((segments[2].start - Vec2::new(2., 0.)).length() < 1e-5);
+    assert!((segments[2].end - Vec2::new(4., 0.)).length() < 1e-5);
+}
+
+#[test]
+fn skip_symbols_move_without_drawing() {
+    let segments = Turtle::default().interpret("FfF");
+
+    assert_eq!(segments.len(), 2);
+    assert!((segments[1].start - Vec2::new(2., 0.)).length() < 1e-5);
+}
+
+#[test]
+fn stochastic_rules_follow_weights() {
+    let system = LSystem::new("A")
+        .with_weighted_rule('A', "B", 3.)
+        .with_weighted_rule('A', "C", 1.);
+    let rng = Rng::with_seed(7);
+
+    let picks: String = (0..4000).map(|_| system.expand(1, &rng)).collect();
+    let b = picks.matches('B').count() as f32 / 4000.;
+    assert!((b - 0.75).abs() < 0.03, "picked B {b} of the time");
+}
+
+#[test]
+fn line_mesh_has_two_vertices_per_segment() {
+    let rng = Rng::with_seed(0);
+    let segments = Turtle::default().interpret(&koch().expand(2, &rng));
+
+    assert_eq!(line_mesh(&segments).count_vertices(), segments.len() * 2);
+}
@@ -0,0 +1,132 @@
+use bevy_turborand::{rng::Rng, SeededCore, TurboRand};
+use heep::{Activation, Network, NetworkError, Perceptron};
+
+const XOR: [([f32; 2], f32); 4] = [
+    ([0., 0.], 0.),
+    ([0., 1.], 1.),
+    ([1., 0.], 1.),
+    ([1., 1.], 0.),
+];
+
+fn xor_network(seed: u64) -> Network {
+    Network::new(2)
+        .with_layer(4, Activation::Tanh)
+        .with_layer(1, Activation::Sigmoid)
+        .with_learning_rate(0.5)
+        .randomized(&Rng::with_seed(seed))
+}
+
+#[test]
+fn perceptron_learns_which_side_of_a_line() {
+    let line = |x: f32| 0.5 * x + 0.2;
+    let rng = Rng::with_seed(1);
+    let mut perceptron = Perceptron::new(2, 0.01, &rng);
+    let point = || [rng.f32_normalized(), rng.f32_normalized()];
+    let label = |[x, y]: [f32; 2]| if y > line(x) { 1. } else { -1. };
+
+    for _ in 0..20_000 {
+        let point = point();
+        perceptron.train(&point, label(point));
+    }
+
+    let correct = (0..1000)
+        .map(|_| point())
+        .filter(|&point| perceptron.predict(&point) == label(point))
+        .count();
+    assert!(correct > 980, "{correct} of 1000 classified correctly");
+}
+
+#[test]
+fn network_learns_xor() {
+    let rng = Rng::with_seed(2);
+    let mut network = xor_network(2);
+
+    for _ in 0..20_000 {
+        let (input, target) = XOR[rng.usize(..XOR.len())];
+        network.train(&input, &[target]);
+    }
+
+    for (input, target) in XOR {
+        let output = network.forward(&input)[0];
+        assert!((output - target).abs() < 0.1, "{input:?} gave {output}");
+    }
+}
+
+#[test]
+fn training_reduces_the_loss() {
+    let mut network = xor_network(3);
+    let loss = |network: &mut Network| -> f32 {
+        XOR.iter()
+            .map(|(input, target)| (network.forward(input)[0] - target).powi(2))
+            .sum()
+    };
+
+    let before = loss(&mut network);
+    for _ in 0..500 {
+        for (input, target) in XOR {
+            network.train(&input, &[target]);
+        }
+    }
+    assert!(loss(&mut network) < before);
+}
+
+#[test]
+fn serialization_round_trips() {
+    let network = xor_network(4)
+        .with_layer(3, Activation::Relu)
+        .with_layer(2, Activation::Identity);
+    let text = network.to_string();
+
+    assert_eq!(text.parse::<Network>(), Ok(network.clone()));
+    assert!(text.starts_with("inputs 2\nlearning_rate 0.5\nlayer 4 tanh\n"));
+    assert_eq!(
+        text.replace("relu", "softmax").parse::<Network>(),
+        Err(NetworkError::UnknownActivation("softmax".into()))
+    );
+    assert!(text
+        .replace("layer 4", "layer 5")
+        .parse::<Network>()
+        .is_err());
+}
+
+#[test]
+fn parameters_round_trip() {
+    let network = xor_network(5);
+    let mut copy = Network::new(2)
+        .with_layer(4, Activation::Tanh)
+        .with_layer(1, Activation::Sigmoid)
+        .with_learning_rate(0.5);
+
+    assert_eq!(network.parameter_count(), 2 * 4 + 4 + 4 + 1);
+    copy.set_parameters(&network.parameters()).unwrap();
+    assert_eq!(copy, network);
+    assert_eq!(
+        copy.set_parameters(&[0.; 3]),
+        Err(NetworkError::WrongParameterCount {
+            expected: 17,
+            found: 3
+        })
+    );
+}
+
+#[test]
+fn zero_sized_layers_forward_and_round_trip() {
+    let rng = Rng::with_seed(6);
+    let network = Network::new(0)
+        .with_layer(3, Activation::Tanh)
+        .with_layer(0, Activation::Relu)
+        .with_layer(2, Activation::Identity)
+        .randomized(&rng);
+
+    // With nothing feeding them, layers output their activated biases.
+    let output = network.forward(&[]);
+    assert_eq!(output, network.layers[2].biases);
+    assert_eq!(network.forward(&[1., 2.]), output);
+
+    let text = network.to_string();
+    assert_eq!(text.parse::<Network>(), Ok(network.clone()));
+
+    let mut trained = network.clone();
+    trained.train(&[], &[0., 0.]);
+    assert_eq!(trained.layers[2].biases.len(), 2);
+}
@@ -0,0 +1,94 @@
+use bevy::prelude::*;
+use heep::{Fbm, Perlin};
+
+/// Points spread over several lattice cells, away from integer coordinates.
+fn samples() -> impl Iterator<Item = Vec3> {
+    (0..2000).map(|i| {
+        let i = i as f32;
+        Vec3::new(i * 0.137, i * 0.071 - 40., i * 0.053 + 7.3)
+    })
+}
+
+#[test]
+fn samples_stay_in_range() {
+    let perlin = Perlin::new(1);
+
+    for point in samples() {
+        for value in [
+            perlin.noise1(point.x),
+            perlin.noise2(point.xy()),
+            perlin.noise3(point),
+            perlin.fbm1(point.x, Fbm::default()),
+            perlin.fbm2(point.xy(), Fbm::default()),
+            perlin.fbm3(point, Fbm::default()),
+        ] {
+            assert!((-1.05..=1.05).contains(&value), "{value} at {point}");
+        }
+    }
+}
+
+#[test]
+fn noise_is_zero_on_the_lattice() {
+    let perlin = Perlin::new(2);
+
+    for i in -20..20 {
+        let i = i as f32;
+        assert_eq!(perlin.noise1(i), 0.);
+        assert_eq!(perlin.noise2(Vec2::new(i, -i)), 0.);
+        assert_eq!(perlin.noise3(Vec3::new(i, 3., -i)), 0.);
+    }
+}
+
+#[test]
+fn same_seed_gives_same_noise() {
+    let (a, b, other) = (Perlin::new(42), Perlin::new(42), Perlin::new(43));
+
+    let differs = samples().any(|point| a.noise3(point) != other.noise3(point));
+    assert!(differs, "different seeds gave identical noise");
+    for point in samples() {
+        assert_eq!(a.noise1(point.x), b.noise1(point.x));
+        assert_eq!(a.noise2(point.xy()), b.noise2(point.xy()));
+        assert_eq!(a.noise3(point), b.noise3(point));
+    }
+}
+
+#[test]
+fn noise_is_continuous() {
+    let perlin = Perlin::new(3);
+    let step = 1e-3;
+
+    for point in samples() {
+        let nudged = point + Vec3::splat(step);
+        assert!((perlin.noise1(point.x) - perlin.noise1(nudged.x)).abs() < 0.01);
+        assert!((perlin.noise2(point.xy()) - perlin.noise2(nudged.xy())).abs() < 0.01);
+        assert!((perlin.noise3(point) - perlin.noise3(nudged)).abs() < 0.01);
+        assert!(
+            (perlin.fbm2(point.xy(), Fbm::default()) - perlin.fbm2(nudged.xy(), Fbm::default()))
+                .abs()
+                < 0.02
+        );
+    }
+}
+
+#[test]
+fn noise_repeats_every_256_units() {
+    let perlin = Perlin::new(4);
+
+    for point in samples().take(100) {
+        let x = point.x.fract() + 3.;
+        assert!((perlin.noise1(x) - perlin.noise1(x + 256.)).abs() < 1e-4);
+    }
+}
+
+#[test]
+fn single_octave_fbm_is_plain_noise() {
+    let perlin = Perlin::new(5);
+    let fbm = Fbm {
+        octaves: 1,
+        ..Default::default()
+    };
+
+    for point in samples().take(100) {
+        assert_eq!(perlin.fbm2(point.xy(), fbm), perlin.noise2(point.xy()));
+    }
+}
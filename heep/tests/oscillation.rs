@@ -0,0 +1,289 @@
+use std::{f32::consts::PI, time::Duration};
+
+use bevy::{
+    prelude::*,
+    time::{TimePlugin, TimeUpdateStrategy},
+};
+use heep::{
+    BodyBundle, BodyIntegrator, Integrator, Mass, OscillationPlugin, Oscillator, Pendulum,
+    PhysicsPlugin, Position, Spring, SpringEnd, Velocity,
+};
+
+const DT: f32 = 1. / 600.;
+
+/// Mass on a spring anchored at the origin, starting stretched along x.
+struct MassSpring {
+    spring: Spring,
+    mass: f32,
+    position: Vec2,
+    velocity: Vec2,
+}
+
+impl MassSpring {
+    fn new() -> Self {
+        Self {
+            spring: Spring::new(
+                SpringEnd::Anchor(Vec2::ZERO),
+                SpringEnd::Anchor(Vec2::ZERO),
+                10.,
+                8.,
+            ),
+            mass: 2.,
+            position: Vec2::new(15., 0.),
+            velocity: Vec2::ZERO,
+        }
+    }
+
+    fn step(&mut self, integrator: Integrator) {
+        let (spring, mass) = (self.spring, self.mass);
+        (self.position, self.velocity) =
+            integrator.step(self.position, self.velocity, DT, |position, velocity| {
+                spring.force(Vec2::ZERO, Vec2::ZERO, position, velocity) / mass
+            });
+    }
+
+    fn energy(&self) -> f32 {
+        0.5 * self.mass * self.velocity.length_squared()
+            + self.spring.potential_energy(Vec2::ZERO, self.position)
+    }
+}
+
+/// The same mass on a spring, simulated by the plugins in a headless `App`
+/// that runs one fixed step per update.
+struct SpringApp {
+    app: App,
+    body: Entity,
+    spring: Spring,
+    mass: f32,
+}
+
+impl SpringApp {
+    fn new(integrator: BodyIntegrator) -> Self {
+        let step = Duration::from_secs_f32(DT);
+        let mut app = App::new();
+        app.add_plugins((TimePlugin, PhysicsPlugin, OscillationPlugin))
+            .insert_resource(integrator)
+            .insert_resource(Time::<Fixed>::from_duration(step))
+            .insert_resource(TimeUpdateStrategy::ManualDuration(step));
+
+        let MassSpring {
+            spring,
+            mass,
+            position,
+            ..
+        } = MassSpring::new();
+        let body = app
+            .world_mut()
+            .spawn(BodyBundle {
+                position: Position(position),
+                mass: Mass(mass),
+                ..Default::default()
+            })
+            .id();
+        let spring = Spring {
+            b: SpringEnd::Entity(body),
+            ..spring
+        };
+        app.world_mut().spawn(spring);
+
+        Self {
+            app,
+            body,
+            spring,
+            mass,
+        }
+    }
+
+    fn step(&mut self) -> (Vec2, Vec2) {
+        self.app.update();
+        let body = self.app.world().entity(self.body);
+        (
+            body.get::<Position>().unwrap().0,
+            body.get::<Velocity>().unwrap().0,
+        )
+    }
+
+    fn energy(&self) -> f32 {
+        let body = self.app.world().entity(self.body);
+        let (position, velocity) = (
+            body.get::<Position>().unwrap().0,
+            body.get::<Velocity>().unwrap().0,
+        );
+        0.5 * self.mass * velocity.length_squared()
+            + self.spring.potential_energy(Vec2::ZERO, position)
+    }
+}
+
+/// Time between the first and second upward crossings of `threshold`.
+fn measure_period(mut step: impl FnMut() -> f32, threshold: f32) -> f32 {
+    let mut previous = step();
+    let mut crossings = Vec::new();
+    let mut time = 0.;
+
+    while crossings.len() < 2 {
+        let value = step();
+        time += DT;
+        if previous < threshold && value >= threshold {
+            crossings.push(time);
+        }
+        previous = value;
+    }
+
+    crossings[1] - crossings[0]
+}
+
+#[test]
+fn spring_period_matches_analytic() {
+    let mut body = MassSpring::new();
+    let expected = 2. * PI * (body.mass / body.spring.stiffness).sqrt();
+    let rest_length = body.spring.rest_length;
+
+    let period = measure_period(
+        || {
+            body.step(Integrator::SemiImplicitEuler);
+            body.position.x
+        },
+        rest_length,
+    );
+
+    assert!(
+        (period - expected).abs() / expected < 0.01,
+        "period {period}, expected {expected}"
+    );
+}
+
+#[test]
+fn spring_energy_is_conserved() {
+    for (integrator, tolerance) in [
+        (Integrator::Rk4, 1e-3),
+        (Integrator::Verlet, 1e-3),
+        (Integrator::SemiImplicitEuler, 1e-2),
+    ] {
+        let mut body = MassSpring::new();
+        let initial = body.energy();
+
+        for _ in 0..(10. / DT) as usize {
+            body.step(integrator);
+            let drift = (body.energy() - initial).abs() / initial;
+            assert!(drift < tolerance, "{integrator:?} drifted by {drift}");
+        }
+    }
+}
+
+#[test]
+fn explicit_euler_gains_energy() {
+    let mut body = MassSpring::new();
+    let initial = body.energy();
+
+    for _ in 0..(10. / DT) as usize {
+        body.step(Integrator::ExplicitEuler);
+    }
+
+    assert!(body.energy() > initial);
+}
+
+#[test]
+fn damped_spring_loses_energy() {
+    let mut body = MassSpring::new();
+    body.spring = body.spring.with_damping(0.5);
+    let initial = body.energy();
+
+    for _ in 0..(10. / DT) as usize {
+        body.step(Integrator::Rk4);
+    }
+
+    assert!(body.energy() < initial * 0.5);
+}
+
+#[test]
+fn plugin_spring_period_matches_analytic() {
+    let mut body = SpringApp::new(BodyIntegrator::SemiImplicitEuler);
+    let expected = 2. * PI * (body.mass / body.spring.stiffness).sqrt();
+    let rest_length = body.spring.rest_length;
+
+    let period = measure_period(|| body.step().0.x, rest_length);
+
+    assert!(
+        (period - expected).abs() / expected < 0.01,
+        "period {period}, expected {expected}"
+    );
+}
+
+#[test]
+fn plugin_spring_energy_stays_bounded() {
+    let mut body = SpringApp::new(BodyIntegrator::SemiImplicitEuler);
+    body.step();
+    let initial = body.energy();
+
+    for _ in 0..(10. / DT) as usize {
+        body.step();
+        let drift = (body.energy() - initial).abs() / initial;
+        assert!(drift < 1e-2, "drifted by {drift}");
+    }
+}
+
+#[test]
+fn plugin_explicit_euler_gains_energy() {
+    let mut body = SpringApp::new(BodyIntegrator::ExplicitEuler);
+    body.step();
+    let initial = body.energy();
+
+    for _ in 0..(10. / DT) as usize {
+        body.step();
+    }
+
+    assert!(body.energy() > initial * 1.01);
+}
+
+#[test]
+fn pendulum_small_angle_period_matches_analytic() {
+    let mut pendulum = Pendulum::new(Vec2::ZERO, 2., 0.05, 9.81);
+    let expected = pendulum.small_angle_period();
+
+    let period = measure_period(
+        || {
+            pendulum.step(Integrator::Rk4, DT);
+            pendulum.angle
+        },
+        0.,
+    );
+
+    assert!(
+        (period - expected).abs() / expected < 0.005,
+        "period {period}, expected {expected}"
+    );
+}
+
+#[test]
+fn pendulum_energy_is_conserved() {
+    let mut pendulum = Pendulum::new(Vec2::ZERO, 2., 1., 9.81);
+    let initial = pendulum.energy();
+
+    for _ in 0..(10. / DT) as usize {
+        pendulum.step(Integrator::Rk4, DT);
+    }
+
+    let drift = (pendulum.energy() - initial).abs() / initial.abs();
+    assert!(drift < 1e-3, "drifted by {drift}");
+}
+
+#[test]
+fn pendulum_bob_hangs_below_pivot() {
+    let pendulum = Pendulum::new(Vec2::new(1., 2.), 3., 0., 9.81);
+    assert!(pendulum.bob().distance(Vec2::new(1., -1.)) < 1e-6);
+}
+
+#[test]
+fn oscillator_follows_sine() {
+    let oscillator = Oscillator {
+        center: Vec2::new(5., 0.),
+        amplitude: Vec2::new(0., 2.),
+        period: 4.,
+        phase: 0.,
+    };
+
+    assert!(oscillator.position(0.).distance(Vec2::new(5., 0.)) < 1e-5);
+    assert!(oscillator.position(1.).distance(Vec2::new(5., 2.)) < 1e-5);
+    assert!(oscillator.position(3.).distance(Vec2::new(5., -2.)) < 1e-5);
+    assert!(oscillator.position(4.5).distance(oscillator.position(0.5)) < 1e-5);
+}
@@ -0,0 +1,106 @@
+use std::time::Duration;
+
+use bevy::{
+    prelude::*,
+    time::{TimePlugin, TimeUpdateStrategy},
+};
+use bevy_turborand::prelude::RngPlugin;
+use heep::{Emitter, Particle, ParticlePlugin, ParticlePool, PhysicsPlugin, Position};
+
+const DT: f32 = 1. / 60.;
+
+fn app() -> App {
+    let step = Duration::from_secs_f32(DT);
+    let mut app = App::new();
+    app.add_plugins((
+        TimePlugin,
+        RngPlugin::new().with_rng_seed(1),
+        PhysicsPlugin,
+        ParticlePlugin,
+    ))
+    .insert_resource(Time::<Fixed>::from_duration(step))
+    .insert_resource(TimeUpdateStrategy::ManualDuration(step));
+    app
+}
+
+/// Entities with a `Position`, other than the emitter.
+fn bodies(app: &mut App, emitter: Entity) -> usize {
+    app.world_mut()
+        .query_filtered::<Entity, With<Position>>()
+        .iter(app.world())
+        .filter(|&entity| entity != emitter)
+        .count()
+}
+
+#[test]
+fn released_particles_leave_the_simulation_until_reused() {
+    let mut app = app();
+    let emitter = app
+        .world_mut()
+        .spawn((Emitter::new(1. / DT, 3. * DT), Position(Vec2::ZERO)))
+        .id();
+
+    for _ in 0..10 {
+        app.update();
+    }
+    assert!(bodies(&mut app, emitter) > 0);
+
+    app.world_mut().entity_mut(emitter).remove::<Emitter>();
+    for _ in 0..10 {
+        app.update();
+    }
+    let free = app.world().resource::<ParticlePool>().free();
+    assert!(free > 0);
+    assert_eq!(bodies(&mut app, emitter), 0);
+
+    app.world_mut()
+        .entity_mut(emitter)
+        .insert(Emitter::new(1. / DT, 3. * DT));
+    app.update();
+    let particles = app
+        .world_mut()
+        .query_filtered::<(), (With<Particle>, With<Position>)>()
+        .iter(app.world())
+        .count();
+    assert_eq!(particles, 1);
+    assert_eq!(app.world().resource::<ParticlePool>().free(), free - 1);
+}
+
+#[test]
+fn pooled_particles_despawned_elsewhere_are_skipped() {
+    let mut app = app();
+    let emitter = app
+        .world_mut()
+        .spawn((Emitter::new(1. / DT, 3. * DT), Position(Vec2::ZERO)))
+        .id();
+    for _ in 0..10 {
+        app.update();
+    }
+    app.world_mut().entity_mut(emitter).remove::<Emitter>();
+    for _ in 0..10 {
+        app.update();
+    }
+    assert!(app.world().resource::<ParticlePool>().free() > 0);
+
+    // Something else clears every pooled particle.
+    let pooled: Vec<_> = app
+        .world_mut()
+        .query_filtered::<Entity, (With<Sprite>, Without<Particle>)>()
+        .iter(app.world())
+        .collect();
+    for entity in pooled {
+        app.world_mut().despawn(entity);
+    }
+
+    app.world_mut()
+        .entity_mut(emitter)
+        .insert(Emitter::new(1. / DT, 3. * DT));
+    app.update();
+    let particles = app
+        .world_mut()
+        .query_filtered::<(), (With<Particle>, With<Position>)>()
+        .iter(app.world())
+        .count();
+    assert_eq!(particles, 1);
+    assert_eq!(app.world().resource::<ParticlePool>().free(), 0);
+}
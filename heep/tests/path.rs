@@ -0,0 +1,74 @@
+use bevy::prelude::*;
+use heep::{SteeringPath, SteeringPathError};
+
+#[test]
+fn parses_sample_path() {
+    let path: SteeringPath = include_str!("../assets/paths/racetrack.path")
+        .parse()
+        .unwrap();
+
+    assert_eq!(path.radius, 4.);
+    assert!(path.looping);
+    assert_eq!(path.points.len(), 12);
+    assert_eq!(path.points[0], Vec2::new(-60., -20.));
+    assert_eq!(path.segments().count(), 12);
+}
+
+#[test]
+fn parses_open_path_with_comments() {
+    let path: SteeringPath = "radius 2.5 # wide enough\n\n0 0\n# halfway\n10 -5\n"
+        .parse()
+        .unwrap();
+
+    assert_eq!(
+        path,
+        SteeringPath {
+            points: vec![Vec2::ZERO, Vec2::new(10., -5.)],
+            radius: 2.5,
+            looping: false,
+        }
+    );
+    assert_eq!(path.segments().count(), 1);
+}
+
+#[test]
+fn rejects_bad_number() {
+    let error = "radius 4\n0 0\n10 ten\n"
+        .parse::<SteeringPath>()
+        .unwrap_err();
+    assert!(matches!(
+        error,
+        SteeringPathError::InvalidNumber { line: 3, .. }
+    ));
+}
+
+#[test]
+fn rejects_missing_radius() {
+    let error = "0 0\n10 0\n".parse::<SteeringPath>().unwrap_err();
+    assert!(matches!(error, SteeringPathError::MissingRadius));
+}
+
+#[test]
+fn rejects_unknown_line() {
+    let error = "radius 4\n0 0 0\n".parse::<SteeringPath>().unwrap_err();
+    assert!(matches!(error, SteeringPathError::InvalidLine { line: 2 }));
+}
+
+#[test]
+fn rejects_single_point() {
+    let error = "radius 4\n0 0\n".parse::<SteeringPath>().unwrap_err();
+    assert!(matches!(error, SteeringPathError::TooFewPoints));
+}
+
+#[test]
+fn follows_only_when_straying() {
+    let path: SteeringPath = "radius 2\n0 0\n100 0\n".parse().unwrap();
+
+    let on_track = path.follow_target(Vec2::new(10., 1.), Vec2::X, 5., 3.);
+    assert_eq!(on_track, None);
+
+    let target = path
+        .follow_target(Vec2::new(10., 5.), Vec2::X, 5., 3.)
+        .unwrap();
+    assert!(target.distance(Vec2::new(18., 0.)) < 1e-5);
+}
@@ -0,0 +1,111 @@
+use std::time::Duration;
+
+use bevy::{
+    prelude::*,
+    time::{TimePlugin, TimeUpdateStrategy},
+};
+use heep::{BodyBundle, Forces, Integrator, PhysicsPlugin, PhysicsSet, Position, Velocity};
+
+const DT: f32 = 1. / 64.;
+const STIFFNESS: f32 = 4.;
+
+#[derive(Component)]
+struct Anchored;
+
+/// Pulls anchored bodies back to the origin with a Hooke's law spring.
+fn pull(mut bodies: Query<(&Position, &mut Forces), With<Anchored>>) {
+    for (position, mut forces) in &mut bodies {
+        forces.apply_force(-position.0 * STIFFNESS);
+    }
+}
+
+/// Headless app stepping a unit mass at `position` once per update.
+fn app(integrator: Integrator, position: Vec2, anchored: bool) -> (App, Entity) {
+    let step = Duration::from_secs_f32(DT);
+    let mut app = App::new();
+    app.add_plugins((TimePlugin, PhysicsPlugin))
+        .add_systems(FixedUpdate, pull.before(PhysicsSet))
+        .insert_resource(integrator)
+        .insert_resource(Time::<Fixed>::from_duration(step))
+        .insert_resource(TimeUpdateStrategy::ManualDuration(step));
+
+    let mut body = app.world_mut().spawn(BodyBundle {
+        position: Position(position),
+        ..Default::default()
+    });
+    if anchored {
+        body.insert(Anchored);
+    }
+    let body = body.id();
+
+    // The first update only starts the clock.
+    app.update();
+    (app, body)
+}
+
+fn state(app: &App, body: Entity) -> (Vec2, Vec2) {
+    let body = app.world().entity(body);
+    (
+        body.get::<Position>().unwrap().0,
+        body.get::<Velocity>().unwrap().0,
+    )
+}
+
+#[test]
+fn second_order_schemes_are_exact_under_constant_force() {
+    for (integrator, tolerance) in [
+        (Integrator::ExplicitEuler, 0.2),
+        (Integrator::SemiImplicitEuler, 0.2),
+        (Integrator::Verlet, 1e-3),
+        (Integrator::Rk4, 1e-3),
+    ] {
+        let (mut app, body) = app(integrator, Vec2::ZERO, false);
+        let steps = 64;
+        for _ in 0..steps {
+            app.world_mut()
+                .get_mut::<Forces>(body)
+                .unwrap()
+                .apply_force(Vec2::new(0., -10.));
+            app.update();
+        }
+
+        let time = steps as f32 * DT;
+        let (position, velocity) = state(&app, body);
+        let expected = -0.5 * 10. * time * time;
+        assert!(
+            (position.y - expected).abs() < tolerance,
+            "{integrator:?} fell to {position}, expected {expected}"
+        );
+        assert!((velocity.y + 10. * time).abs() < 1e-3, "{integrator:?}");
+    }
+}
+
+#[test]
+fn verlet_conserves_spring_energy_better_than_euler() {
+    let drift = |integrator| {
+        let (mut app, body) = app(integrator, Vec2::new(2., 0.), true);
+        let energy = |(position, velocity): (Vec2, Vec2)| {
+            0.5 * velocity.length_squared() + 0.5 * STIFFNESS * position.length_squared()
+        };
+        let initial = energy(state(&app, body));
+
+        let mut worst: f32 = 0.;
+        for _ in 0..(20. / DT) as usize {
+            app.update();
+            worst = worst.max((energy(state(&app, body)) - initial).abs() / initial);
+        }
+        worst
+    };
+
+    let (explicit, semi_implicit, verlet) = (
+        drift(Integrator::ExplicitEuler),
+        drift(Integrator::SemiImplicitEuler),
+        drift(Integrator::Verlet),
+    );
+    assert!(explicit > 1., "explicit Euler drifted by {explicit}");
+    assert!(
+        semi_implicit < 0.05,
+        "semi-implicit Euler drifted by {semi_implicit}"
+    );
+    assert!(verlet < semi_implicit / 10., "Verlet drifted by {verlet}");
+}
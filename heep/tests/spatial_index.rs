@@ -0,0 +1,124 @@
+use bevy::prelude::*;
+use bevy_turborand::{rng::Rng, SeededCore, TurboRand};
+use heep::{SpatialEntry, SpatialIndex};
+
+fn entries(count: usize) -> Vec<SpatialEntry> {
+    let rng = Rng::with_seed(11);
+    (0..count)
+        .map(|i| SpatialEntry {
+            entity: Entity::from_raw(i as u32),
+            position: Vec2::new(rng.f32_normalized(), rng.f32_normalized()) * 200.,
+            // Every fourth entry is large enough to span several cells.
+            extents: if i % 4 == 0 {
+                Vec2::new(rng.f32() * 30., rng.f32() * 30.)
+            } else {
+                Vec2::ZERO
+            },
+        })
+        .collect()
+}
+
+fn index(entries: &[SpatialEntry]) -> SpatialIndex {
+    let mut index = SpatialIndex::new(10.);
+    for entry in entries {
+        index.insert(entry.entity, entry.position, entry.extents);
+    }
+    index
+}
+
+fn sorted(entries: impl IntoIterator<Item = SpatialEntry>) -> Vec<u32> {
+    let mut indices: Vec<_> = entries
+        .into_iter()
+        .map(|entry| entry.entity.index())
+        .collect();
+    indices.sort_unstable();
+    indices
+}
+
+#[test]
+fn radius_query_matches_brute_force() {
+    let entries = entries(2000);
+    let index = index(&entries);
+
+    for (center, radius) in [
+        (Vec2::ZERO, 15.),
+        (Vec2::new(120., -40.), 37.5),
+        (Vec2::new(-195., 195.), 4.),
+    ] {
+        let expected = entries.iter().copied().filter(|entry| {
+            let closest = center.clamp(entry.bounds().min, entry.bounds().max);
+            closest.distance(center) <= radius
+        });
+
+        assert_eq!(sorted(index.query_radius(center, radius)), sorted(expected));
+    }
+}
+
+#[test]
+fn aabb_query_matches_brute_force() {
+    let entries = entries(2000);
+    let index = index(&entries);
+
+    for rect in [
+        Rect::new(-10., -10., 10., 10.),
+        Rect::new(50., -100., 120., 0.),
+        Rect::new(-300., -300., 300., 300.),
+    ] {
+        let expected = entries.iter().copied().filter(|entry| {
+            let bounds = entry.bounds();
+            bounds.min.x <= rect.max.x
+                && rect.min.x <= bounds.max.x
+                && bounds.min.y <= rect.max.y
+                && rect.min.y <= bounds.max.y
+        });
+
+        assert_eq!(sorted(index.query_aabb(rect)), sorted(expected));
+    }
+}
+
+#[test]
+fn nearest_matches_brute_force() {
+    let entries = entries(2000);
+    let index = index(&entries);
+
+    for (point, k) in [
+        (Vec2::ZERO, 1),
+        (Vec2::new(80., 80.), 10),
+        (Vec2::new(-500., 0.), 25),
+    ] {
+        let mut expected = entries.clone();
+        expected.sort_by(|a, b| {
+            a.position
+                .distance_squared(point)
+                .total_cmp(&b.position.distance_squared(point))
+        });
+        expected.truncate(k);
+
+        let nearest = index.nearest(point, k);
+        let distances = |entries: &[SpatialEntry]| -> Vec<f32> {
+            entries
+                .iter()
+                .map(|entry| entry.position.distance(point))
+                .collect()
+        };
+        assert_eq!(distances(&nearest), distances(&expected));
+    }
+}
+
+#[test]
+fn nearest_returns_everything_when_k_exceeds_len() {
+    let entries = entries(5);
+    let index = index(&entries);
+
+    assert_eq!(index.nearest(Vec2::ZERO, 10).len(), 5);
+}
+
+#[test]
+fn clear_empties_the_index() {
+    let entries = entries(100);
+    let mut index = index(&entries);
+    index.clear();
+
+    assert!(index.is_empty());
+    assert!(index.query_radius(Vec2::ZERO, 1000.).is_empty());
+}
@@ -0,0 +1,87 @@
+use bevy::prelude::*;
+use heep::{Vehicle, Wander};
+
+const VEHICLE: Vehicle = Vehicle {
+    max_speed: 10.,
+    max_force: 100.,
+};
+
+#[test]
+fn seek_is_limited_to_max_force() {
+    let vehicle = Vehicle::new(10., 0.5);
+    let force = vehicle.seek(Vec2::ZERO, Vec2::new(-10., 0.), Vec2::new(100., 0.));
+
+    assert!((force.length() - 0.5).abs() < 1e-6);
+    assert!(force.x > 0.);
+}
+
+#[test]
+fn arrive_at_full_speed_outside_slowing_radius() {
+    let force = VEHICLE.arrive(Vec2::ZERO, Vec2::ZERO, Vec2::new(50., 0.), 10.);
+    assert!((force - Vec2::new(10., 0.)).length() < 1e-5);
+}
+
+#[test]
+fn arrive_slows_inside_slowing_radius() {
+    let halfway = VEHICLE.arrive(Vec2::ZERO, Vec2::ZERO, Vec2::new(0., 5.), 10.);
+    assert!((halfway - Vec2::new(0., 5.)).length() < 1e-5);
+
+    let close = VEHICLE.arrive(Vec2::ZERO, Vec2::ZERO, Vec2::new(0., 1.), 10.);
+    assert!((close - Vec2::new(0., 1.)).length() < 1e-5);
+}
+
+#[test]
+fn arrive_brakes_at_target() {
+    let force = VEHICLE.arrive(Vec2::ZERO, Vec2::new(3., 4.), Vec2::ZERO, 10.);
+    assert!((force - Vec2::new(-3., -4.)).length() < 1e-5);
+}
+
+#[test]
+fn flee_ignores_targets_outside_radius() {
+    let force = VEHICLE.flee(Vec2::ZERO, Vec2::ZERO, Vec2::new(20., 0.), 10.);
+    assert_eq!(force, Vec2::ZERO);
+
+    let force = VEHICLE.flee(Vec2::ZERO, Vec2::ZERO, Vec2::new(5., 0.), 10.);
+    assert!((force - Vec2::new(-10., 0.)).length() < 1e-5);
+}
+
+#[test]
+fn pursue_leads_a_moving_target() {
+    let force = VEHICLE.pursue(
+        Vec2::ZERO,
+        Vec2::ZERO,
+        Vec2::new(10., 0.),
+        Vec2::new(0., 10.),
+    );
+    assert!(force.x > 0. && force.y > 0.);
+}
+
+#[test]
+fn wander_target_lies_on_circle_ahead() {
+    let position = Vec2::new(3., -2.);
+    let velocity = Vec2::new(0., 4.);
+    let center = position + Vec2::Y * 8.;
+
+    for step in 0..16 {
+        let wander = Wander {
+            distance: 8.,
+            radius: 4.,
+            angle: step as f32 * 0.4,
+            ..Default::default()
+        };
+        let target = wander.target(position, velocity);
+        assert!((target.distance(center) - 4.).abs() < 1e-4);
+    }
+}
+
+#[test]
+fn wander_with_zero_angle_points_straight_ahead() {
+    let wander = Wander {
+        distance: 8.,
+        radius: 4.,
+        angle: 0.,
+        ..Default::default()
+    };
+    let target = wander.target(Vec2::ZERO, Vec2::new(-2., 0.));
+    assert!((target - Vec2::new(-12., 0.)).length() < 1e-5);
+}
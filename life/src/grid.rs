@@ -0,0 +1,101 @@
+use bevy::prelude::*;
+
+use crate::{Pattern, Rule};
+
+/// A toroidal grid of cells stepped under a life-like [`Rule`]. Row zero is
+/// the top of the grid; coordinates outside it wrap around.
+#[derive(Resource, Clone, Debug)]
+pub struct Life {
+    pub rule: Rule,
+    width: usize,
+    height: usize,
+    cells: Vec<bool>,
+    generation: usize,
+}
+
+impl Life {
+    pub fn new(width: usize, height: usize, rule: Rule) -> Self {
+        Self {
+            rule,
+            width,
+            height,
+            cells: vec![false; width * height],
+            generation: 0,
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn generation(&self) -> usize {
+        self.generation
+    }
+
+    /// Cells in row-major order, starting from the top left.
+    pub fn cells(&self) -> &[bool] {
+        &self.cells
+    }
+
+    pub fn population(&self) -> usize {
+        self.cells.iter().filter(|&&alive| alive).count()
+    }
+
+    fn index(&self, x: isize, y: isize) -> usize {
+        let x = x.rem_euclid(self.width as isize) as usize;
+        let y = y.rem_euclid(self.height as isize) as usize;
+        y * self.width + x
+    }
+
+    pub fn get(&self, x: isize, y: isize) -> bool {
+        self.cells[self.index(x, y)]
+    }
+
+    pub fn set(&mut self, x: isize, y: isize, alive: bool) {
+        let index = self.index(x, y);
+        self.cells[index] = alive;
+    }
+
+    pub fn clear(&mut self) {
+        self.cells.fill(false);
+        self.generation = 0;
+    }
+
+    /// Sets every cell from `f`, e.g. a random source.
+    pub fn fill_with(&mut self, mut f: impl FnMut() -> bool) {
+        self.cells.iter_mut().for_each(|cell| *cell = f());
+        self.generation = 0;
+    }
+
+    /// Brings the live cells of `pattern` to life with its top left corner
+    /// at `(x, y)`.
+    pub fn place(&mut self, pattern: &Pattern, x: isize, y: isize) {
+        for &(dx, dy) in &pattern.cells {
+            self.set(x + dx as isize, y + dy as isize, true);
+        }
+    }
+
+    pub fn neighbours(&self, x: isize, y: isize) -> u8 {
+        let mut count = 0;
+        for dy in -1..=1 {
+            for dx in -1..=1 {
+                if (dx, dy) != (0, 0) && self.get(x + dx, y + dy) {
+                    count += 1;
+                }
+            }
+        }
+        count
+    }
+
+    pub fn step(&mut self) {
+        self.cells = (0..self.height as isize)
+            .flat_map(|y| (0..self.width as isize).map(move |x| (x, y)))
+            .map(|(x, y)| self.rule.next(self.get(x, y), self.neighbours(x, y)))
+            .collect();
+        self.generation += 1;
+    }
+}
@@ -0,0 +1,9 @@
+mod grid;
+mod pattern;
+mod rule;
+mod sketch;
+
+pub use grid::*;
+pub use pattern::*;
+pub use rule::*;
+pub use sketch::*;
@@ -0,0 +1,14 @@
+use bevy::prelude::*;
+use heep::Sketch;
+use life::LifePlugin;
+
+fn main() {
+    App::new()
+        .add_plugins(
+            Sketch::new("heep - Life")
+                .with_world_height(LifePlugin::HEIGHT as f32)
+                .with_background(Color::BLACK),
+        )
+        .add_plugins(LifePlugin)
+        .run();
+}
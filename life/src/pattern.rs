@@ -0,0 +1,186 @@
+use std::fmt;
+
+use bevy::{
+    asset::{io::Reader, AssetLoader, AsyncReadExt, LoadContext},
+    prelude::*,
+};
+
+use crate::{Rule, RuleError};
+
+/// A set of live cells, relative to the pattern's top left corner, read
+/// from an RLE (`.rle`) or plaintext (`.cells`) file.
+#[derive(Asset, TypePath, Clone, Debug, Default, PartialEq)]
+pub struct Pattern {
+    pub name: Option<String>,
+    pub width: usize,
+    pub height: usize,
+    pub cells: Vec<(usize, usize)>,
+    /// Rule given in an RLE header, if any.
+    pub rule: Option<Rule>,
+}
+
+#[derive(Debug)]
+pub enum PatternError {
+    Io(std::io::Error),
+    Rule(RuleError),
+    InvalidHeader(String),
+    UnexpectedCharacter { line: usize, character: char },
+    UnknownFormat,
+}
+
+impl fmt::Display for PatternError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Io(error) => write!(f, "could not read pattern: {error}"),
+            Self::Rule(error) => error.fmt(f),
+            Self::InvalidHeader(header) => write!(f, "invalid RLE header `{header}`"),
+            Self::UnexpectedCharacter { line, character } => {
+                write!(f, "line {line}: unexpected `{character}`")
+            }
+            Self::UnknownFormat => write!(f, "patterns must be `.rle` or `.cells` files"),
+        }
+    }
+}
+
+impl std::error::Error for PatternError {}
+
+impl Pattern {
+    fn from_cells(name: Option<String>, cells: Vec<(usize, usize)>, rule: Option<Rule>) -> Self {
+        Self {
+            name,
+            width: cells.iter().map(|&(x, _)| x + 1).max().unwrap_or(0),
+            height: cells.iter().map(|&(_, y)| y + 1).max().unwrap_or(0),
+            cells,
+            rule,
+        }
+    }
+
+    /// Parses run length encoding: `#` comment lines, an `x = .., y = ..`
+    /// header with an optional `rule`, then runs of `b` (dead), `o` (alive)
+    /// and `$` (end of row) terminated by `!`.
+    pub fn from_rle(source: &str) -> Result<Self, PatternError> {
+        let mut name = None;
+        let mut rule = None;
+        let mut cells = Vec::new();
+        let (mut x, mut y) = (0, 0);
+        let mut run = 0;
+        let mut header = false;
+
+        'lines: for (index, line) in source.lines().enumerate() {
+            let line = line.trim();
+            if let Some(comment) = line.strip_prefix("#N") {
+                name = Some(comment.trim().to_string());
+                continue;
+            }
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            if !header {
+                header = true;
+                for field in line.split(',') {
+                    let (key, value) = field
+                        .split_once('=')
+                        .ok_or_else(|| PatternError::InvalidHeader(line.to_string()))?;
+                    if key.trim() == "rule" {
+                        rule = Some(value.parse().map_err(PatternError::Rule)?);
+                    }
+                }
+                continue;
+            }
+
+            for character in line.chars() {
+                match character {
+                    '0'..='9' => {
+                        run = run * 10 + character as usize - '0' as usize;
+                        continue;
+                    }
+                    'b' | '.' => x += run.max(1),
+                    '$' => {
+                        y += run.max(1);
+                        x = 0;
+                    }
+                    '!' => break 'lines,
+                    c if c.is_ascii_alphabetic() => {
+                        cells.extend((x..x + run.max(1)).map(|x| (x, y)));
+                        x += run.max(1);
+                    }
+                    c if c.is_whitespace() => continue,
+                    character => {
+                        return Err(PatternError::UnexpectedCharacter {
+                            line: index + 1,
+                            character,
+                        })
+                    }
+                }
+                run = 0;
+            }
+        }
+
+        Ok(Self::from_cells(name, cells, rule))
+    }
+
+    /// Parses the plaintext format: `!` comment lines, then one line per row
+    /// with `.` for dead and `O` or `*` for live cells.
+    pub fn from_plaintext(source: &str) -> Result<Self, PatternError> {
+        let mut name = None;
+        let mut cells = Vec::new();
+        let mut y = 0;
+
+        for (index, line) in source.lines().enumerate() {
+            if let Some(comment) = line.strip_prefix('!') {
+                if let Some(pattern_name) = comment.strip_prefix("Name:") {
+                    name = Some(pattern_name.trim().to_string());
+                }
+                continue;
+            }
+
+            for (x, character) in line.trim_end().chars().enumerate() {
+                match character {
+                    '.' => {}
+                    'O' | '*' => cells.push((x, y)),
+                    character => {
+                        return Err(PatternError::UnexpectedCharacter {
+                            line: index + 1,
+                            character,
+                        })
+                    }
+                }
+            }
+            y += 1;
+        }
+
+        Ok(Self::from_cells(name, cells, None))
+    }
+}
+
+#[derive(Default)]
+pub struct PatternLoader;
+
+impl AssetLoader for PatternLoader {
+    type Asset = Pattern;
+    type Settings = ();
+    type Error = PatternError;
+
+    async fn load<'a>(
+        &'a self,
+        reader: &'a mut Reader<'_>,
+        _settings: &'a (),
+        load_context: &'a mut LoadContext<'_>,
+    ) -> Result<Pattern, PatternError> {
+        let mut source = String::new();
+        reader
+            .read_to_string(&mut source)
+            .await
+            .map_err(PatternError::Io)?;
+
+        match load_context.path().extension().and_then(|e| e.to_str()) {
+            Some("rle") => Pattern::from_rle(&source),
+            Some("cells") => Pattern::from_plaintext(&source),
+            _ => Err(PatternError::UnknownFormat),
+        }
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &["rle", "cells"]
+    }
+}
@@ -0,0 +1,98 @@
+use std::{fmt, str::FromStr};
+
+/// A life-like birth/survival rule. Bit `n` of each mask is set when a cell
+/// with `n` live neighbours is born or survives.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Rule {
+    pub birth: u16,
+    pub survival: u16,
+}
+
+impl Rule {
+    pub const CONWAY: Self = Self::new(&[3], &[2, 3]);
+    pub const HIGHLIFE: Self = Self::new(&[3, 6], &[2, 3]);
+    pub const DAY_AND_NIGHT: Self = Self::new(&[3, 6, 7, 8], &[3, 4, 6, 7, 8]);
+
+    pub const fn new(birth: &[u8], survival: &[u8]) -> Self {
+        Self {
+            birth: Self::mask(birth),
+            survival: Self::mask(survival),
+        }
+    }
+
+    const fn mask(counts: &[u8]) -> u16 {
+        let mut mask = 0;
+        let mut i = 0;
+        while i < counts.len() {
+            mask |= 1 << counts[i];
+            i += 1;
+        }
+        mask
+    }
+
+    pub fn next(&self, alive: bool, neighbours: u8) -> bool {
+        let mask = if alive { self.survival } else { self.birth };
+        mask >> neighbours & 1 == 1
+    }
+}
+
+impl Default for Rule {
+    fn default() -> Self {
+        Self::CONWAY
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RuleError(pub String);
+
+impl fmt::Display for RuleError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "invalid rule `{}`, expected e.g. `B3/S23`", self.0)
+    }
+}
+
+impl std::error::Error for RuleError {}
+
+/// Parses `B3/S23` notation, in either order and any case, as well as the
+/// older `23/3` survival/birth form.
+impl FromStr for Rule {
+    type Err = RuleError;
+
+    fn from_str(source: &str) -> Result<Self, Self::Err> {
+        let error = || RuleError(source.to_string());
+        let counts = |digits: &str| {
+            digits
+                .chars()
+                .try_fold(0u16, |mask, digit| match digit.to_digit(10) {
+                    Some(count) if count <= 8 => Ok(mask | 1 << count),
+                    _ => Err(error()),
+                })
+        };
+
+        let source = source.trim().to_ascii_uppercase();
+        let (first, second) = source.split_once('/').ok_or_else(error)?;
+        let (birth, survival) = match (first.split_at_checked(1), second.split_at_checked(1)) {
+            (Some(("B", birth)), Some(("S", survival))) => (birth, survival),
+            (Some(("S", survival)), Some(("B", birth))) => (birth, survival),
+            // Unprefixed rules list survival first.
+            _ => (second, first),
+        };
+
+        Ok(Self {
+            birth: counts(birth)?,
+            survival: counts(survival)?,
+        })
+    }
+}
+
+impl fmt::Display for Rule {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let digits = |mask: u16| -> String {
+            (0..=8)
+                .filter(|count| mask >> count & 1 == 1)
+                .map(|count| char::from(b'0' + count as u8))
+                .collect()
+        };
+        write!(f, "B{}/S{}", digits(self.birth), digits(self.survival))
+    }
+}
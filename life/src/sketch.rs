@@ -0,0 +1,229 @@
+use bevy::{
+    prelude::*,
+    render::{
+        render_asset::RenderAssetUsages,
+        render_resource::{Extent3d, TextureDimension, TextureFormat},
+        texture::ImageSampler,
+    },
+    window::PrimaryWindow,
+};
+use bevy_turborand::{DelegatedRng, GlobalRng};
+
+use crate::{Life, Pattern, PatternLoader, Rule};
+
+#[derive(Resource)]
+struct StepTimer(Timer);
+
+#[derive(Resource, Default)]
+struct Paused(bool);
+
+/// The texture cells are drawn into, one pixel each.
+#[derive(Resource)]
+struct Canvas(Handle<Image>);
+
+/// Pattern waiting to be placed once it has loaded.
+#[derive(Resource)]
+struct PendingPattern(Handle<Pattern>);
+
+#[derive(Component)]
+struct StatusLabel;
+
+/// Runs [`Life`] on a timer and draws it into a texture.
+///
+/// Left click draws and right click erases cells. Space pauses, `N` steps
+/// once while paused, `C` clears, `R` randomizes and `1`-`3` switch between
+/// Conway, HighLife and Day & Night.
+pub struct LifePlugin;
+
+impl Plugin for LifePlugin {
+    fn build(&self, app: &mut App) {
+        app.init_asset::<Pattern>();
+        app.init_asset_loader::<PatternLoader>();
+        app.insert_resource(StepTimer(Timer::from_seconds(
+            Self::STEP_SECONDS,
+            TimerMode::Repeating,
+        )));
+        app.init_resource::<Paused>();
+        app.insert_resource(Life::new(Self::WIDTH, Self::HEIGHT, Rule::CONWAY));
+        app.add_systems(Startup, Self::setup);
+        app.add_systems(
+            Update,
+            (
+                Self::place_pattern.run_if(resource_exists::<PendingPattern>),
+                Self::controls,
+                Self::edit,
+                Self::step,
+                Self::draw,
+                Self::label,
+            )
+                .chain(),
+        );
+    }
+}
+
+impl LifePlugin {
+    pub const WIDTH: usize = 160;
+    pub const HEIGHT: usize = 90;
+    const STEP_SECONDS: f32 = 0.1;
+    const PATTERN: &'static str = "patterns/gosper_glider_gun.rle";
+    const ALIVE: [u8; 4] = [240, 240, 220, 255];
+    const DEAD: [u8; 4] = [0, 0, 0, 255];
+
+    fn setup(
+        mut commands: Commands,
+        mut images: ResMut<Assets<Image>>,
+        asset_server: Res<AssetServer>,
+    ) {
+        let mut image = Image::new_fill(
+            Extent3d {
+                width: Self::WIDTH as u32,
+                height: Self::HEIGHT as u32,
+                depth_or_array_layers: 1,
+            },
+            TextureDimension::D2,
+            &Self::DEAD,
+            TextureFormat::Rgba8UnormSrgb,
+            RenderAssetUsages::default(),
+        );
+        image.sampler = ImageSampler::nearest();
+        let image = images.add(image);
+
+        commands.spawn(SpriteBundle {
+            texture: image.clone(),
+            sprite: Sprite {
+                custom_size: Some(Vec2::new(Self::WIDTH as f32, Self::HEIGHT as f32)),
+                ..Default::default()
+            },
+            ..Default::default()
+        });
+        commands.insert_resource(Canvas(image));
+        commands.insert_resource(PendingPattern(asset_server.load(Self::PATTERN)));
+
+        commands.spawn((
+            TextBundle::from_section("", TextStyle::default()).with_style(Style {
+                position_type: PositionType::Absolute,
+                bottom: Val::Px(10.),
+                left: Val::Px(10.),
+                ..Default::default()
+            }),
+            StatusLabel,
+        ));
+    }
+
+    fn place_pattern(
+        mut commands: Commands,
+        pending: Res<PendingPattern>,
+        patterns: Res<Assets<Pattern>>,
+        mut life: ResMut<Life>,
+    ) {
+        let Some(pattern) = patterns.get(&pending.0) else {
+            return;
+        };
+
+        if let Some(rule) = pattern.rule {
+            life.rule = rule;
+        }
+        let x = (Self::WIDTH as isize - pattern.width as isize) / 2;
+        let y = (Self::HEIGHT as isize - pattern.height as isize) / 2;
+        life.place(pattern, x, y);
+        commands.remove_resource::<PendingPattern>();
+    }
+
+    fn controls(
+        keyboard_input: Res<ButtonInput<KeyCode>>,
+        mut paused: ResMut<Paused>,
+        mut life: ResMut<Life>,
+        mut rng: ResMut<GlobalRng>,
+    ) {
+        if keyboard_input.just_pressed(KeyCode::Space) {
+            paused.0 = !paused.0;
+        }
+        if paused.0 && keyboard_input.just_pressed(KeyCode::KeyN) {
+            life.step();
+        }
+        if keyboard_input.just_pressed(KeyCode::KeyC) {
+            life.clear();
+        }
+        if keyboard_input.just_pressed(KeyCode::KeyR) {
+            life.fill_with(|| rng.chance(0.25));
+        }
+
+        for (key, rule) in [
+            (KeyCode::Digit1, Rule::CONWAY),
+            (KeyCode::Digit2, Rule::HIGHLIFE),
+            (KeyCode::Digit3, Rule::DAY_AND_NIGHT),
+        ] {
+            if keyboard_input.just_pressed(key) {
+                life.rule = rule;
+            }
+        }
+    }
+
+    fn edit(
+        mouse_input: Res<ButtonInput<MouseButton>>,
+        window: Query<&Window, With<PrimaryWindow>>,
+        camera: Query<(&Camera, &GlobalTransform)>,
+        mut life: ResMut<Life>,
+    ) {
+        let alive = if mouse_input.pressed(MouseButton::Left) {
+            true
+        } else if mouse_input.pressed(MouseButton::Right) {
+            false
+        } else {
+            return;
+        };
+
+        let (camera, camera_transform) = camera.single();
+        let Some(point) = window
+            .single()
+            .cursor_position()
+            .and_then(|cursor| camera.viewport_to_world_2d(camera_transform, cursor))
+        else {
+            return;
+        };
+
+        let x = (point.x + Self::WIDTH as f32 / 2.).floor();
+        let y = (Self::HEIGHT as f32 / 2. - point.y).floor();
+        if (0. ..Self::WIDTH as f32).contains(&x) && (0. ..Self::HEIGHT as f32).contains(&y) {
+            life.set(x as isize, y as isize, alive);
+        }
+    }
+
+    fn step(
+        time: Res<Time>,
+        mut timer: ResMut<StepTimer>,
+        paused: Res<Paused>,
+        mut life: ResMut<Life>,
+    ) {
+        if timer.0.tick(time.delta()).just_finished() && !paused.0 {
+            life.step();
+        }
+    }
+
+    fn draw(life: Res<Life>, canvas: Res<Canvas>, mut images: ResMut<Assets<Image>>) {
+        if !life.is_changed() {
+            return;
+        }
+        let Some(image) = images.get_mut(&canvas.0) else {
+            return;
+        };
+
+        for (pixel, &alive) in image.data.chunks_exact_mut(4).zip(life.cells()) {
+            pixel.copy_from_slice(if alive { &Self::ALIVE } else { &Self::DEAD });
+        }
+    }
+
+    fn label(life: Res<Life>, paused: Res<Paused>, mut label: Query<&mut Text, With<StatusLabel>>) {
+        if !life.is_changed() && !paused.is_changed() {
+            return;
+        }
+
+        label.single_mut().sections[0].value = format!(
+            "{} | generation {} | population {}{}",
+            life.rule,
+            life.generation(),
+            life.population(),
+            if paused.0 { " | paused" } else { "" },
+        );
+    }
+}
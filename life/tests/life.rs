@@ -0,0 +1,144 @@
+use life::{Life, Pattern, Rule};
+
+const GLIDER: &str = "#N Glider\nx = 3, y = 3, rule = B3/S23\nbob$2bo$3o!";
+
+fn life_with(pattern: &Pattern, width: usize, height: usize, rule: Rule) -> Life {
+    let mut life = Life::new(width, height, rule);
+    life.place(pattern, 1, 1);
+    life
+}
+
+fn live_cells(life: &Life) -> Vec<(usize, usize)> {
+    life.cells()
+        .iter()
+        .enumerate()
+        .filter(|(_, &alive)| alive)
+        .map(|(index, _)| (index % life.width(), index / life.width()))
+        .collect()
+}
+
+#[test]
+fn parses_rule_strings() {
+    assert_eq!("B3/S23".parse(), Ok(Rule::CONWAY));
+    assert_eq!("s23/b3".parse(), Ok(Rule::CONWAY));
+    assert_eq!("23/3".parse(), Ok(Rule::CONWAY));
+    assert_eq!("B36/S23".parse(), Ok(Rule::HIGHLIFE));
+    assert_eq!("B3678/S34678".parse(), Ok(Rule::DAY_AND_NIGHT));
+    assert_eq!(Rule::HIGHLIFE.to_string(), "B36/S23");
+    assert!("B9/S23".parse::<Rule>().is_err());
+    assert!("B3S23".parse::<Rule>().is_err());
+}
+
+#[test]
+fn parses_rle() {
+    let glider = Pattern::from_rle(GLIDER).unwrap();
+
+    assert_eq!(glider.name.as_deref(), Some("Glider"));
+    assert_eq!(glider.rule, Some(Rule::CONWAY));
+    assert_eq!((glider.width, glider.height), (3, 3));
+    assert_eq!(glider.cells, [(1, 0), (2, 1), (0, 2), (1, 2), (2, 2)]);
+
+    let gun = Pattern::from_rle(include_str!("../assets/patterns/gosper_glider_gun.rle")).unwrap();
+    assert_eq!((gun.width, gun.height), (36, 9));
+    assert_eq!(gun.cells.len(), 36);
+}
+
+#[test]
+fn parses_plaintext() {
+    let pulsar = Pattern::from_plaintext(include_str!("../assets/patterns/pulsar.cells")).unwrap();
+
+    assert_eq!(pulsar.name.as_deref(), Some("Pulsar"));
+    assert_eq!((pulsar.width, pulsar.height), (13, 13));
+    assert_eq!(pulsar.cells.len(), 48);
+    assert!(Pattern::from_plaintext(".O.\n.x.").is_err());
+}
+
+#[test]
+fn block_is_still() {
+    let block = Pattern::from_plaintext("OO\nOO").unwrap();
+    let mut life = life_with(&block, 6, 6, Rule::CONWAY);
+    let start = live_cells(&life);
+
+    life.step();
+    assert_eq!(live_cells(&life), start);
+}
+
+#[test]
+fn oscillators_return_after_their_period() {
+    let blinker = Pattern::from_plaintext("OOO").unwrap();
+    let pulsar = Pattern::from_plaintext(include_str!("../assets/patterns/pulsar.cells")).unwrap();
+
+    for (pattern, period) in [(blinker, 2), (pulsar, 3)] {
+        let mut life = life_with(&pattern, 20, 20, Rule::CONWAY);
+        let start = live_cells(&life);
+
+        for generation in 1..=period {
+            life.step();
+            assert_eq!(live_cells(&life) == start, generation == period);
+        }
+    }
+}
+
+#[test]
+fn glider_moves_diagonally() {
+    let glider = Pattern::from_rle(GLIDER).unwrap();
+    let mut life = life_with(&glider, 12, 12, Rule::CONWAY);
+    let start = live_cells(&life);
+
+    for _ in 0..4 {
+        life.step();
+    }
+
+    let moved: Vec<_> = start.iter().map(|&(x, y)| (x + 1, y + 1)).collect();
+    assert_eq!(live_cells(&life), moved);
+    assert_eq!(life.generation(), 4);
+}
+
+#[test]
+fn glider_wraps_around_the_torus() {
+    let glider = Pattern::from_rle(GLIDER).unwrap();
+    let mut life = life_with(&glider, 8, 8, Rule::CONWAY);
+    let start = live_cells(&life);
+
+    // A glider travels one cell diagonally every four generations.
+    for _ in 0..4 * 8 {
+        life.step();
+        assert_eq!(life.population(), 5);
+    }
+    assert_eq!(live_cells(&life), start);
+}
+
+#[test]
+fn highlife_replicator_copies_itself() {
+    let replicator =
+        Pattern::from_rle("x = 5, y = 5, rule = B36/S23\n2b3o$bo2bo$o3bo$o2bo$3o!").unwrap();
+    let mut life = life_with(&replicator, 40, 40, replicator.rule.unwrap());
+    let mut conway = life_with(&replicator, 40, 40, Rule::CONWAY);
+
+    for _ in 0..12 {
+        life.step();
+        conway.step();
+    }
+
+    // After twelve generations HighLife holds two copies of the replicator.
+    assert_eq!(life.population(), 24);
+    assert_ne!(live_cells(&life), live_cells(&conway));
+}
+
+#[test]
+fn day_and_night_is_symmetric_under_inversion() {
+    let glider = Pattern::from_rle(GLIDER).unwrap();
+    let mut life = life_with(&glider, 10, 10, Rule::DAY_AND_NIGHT);
+    let mut inverted = life.clone();
+    let cells = life.cells().to_vec();
+    let mut cells = cells.into_iter().map(|alive| !alive);
+    inverted.fill_with(|| cells.next().unwrap());
+
+    for _ in 0..5 {
+        life.step();
+        inverted.step();
+
+        let flipped: Vec<_> = inverted.cells().iter().map(|alive| !alive).collect();
+        assert_eq!(life.cells(), flipped);
+    }
+}
@@ -0,0 +1,38 @@
+use bevy::prelude::*;
+
+use crate::{gutter::GutterPlugin, paddle::PaddlePlugin};
+
+/// Layout of the playing field for a window of a given size, shared by the
+/// game and the headless training matches.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Arena {
+    pub half_size: Vec2,
+}
+
+impl Arena {
+    /// The default 1280x720 window, which the neural AI is trained in.
+    pub const DEFAULT: Self = Self {
+        half_size: Vec2::new(640., 360.),
+    };
+
+    pub fn new(window: &Window) -> Self {
+        Self {
+            half_size: window.resolution.size() / 2.,
+        }
+    }
+
+    /// Distance of both paddles from the center line.
+    pub fn paddle_x(&self) -> f32 {
+        self.half_size.x - PaddlePlugin::PADDING
+    }
+
+    /// Highest a paddle's center may go before it touches a gutter.
+    pub fn max_paddle_y(&self) -> f32 {
+        self.half_size.y - GutterPlugin::HEIGHT - PaddlePlugin::HEIGHT / 2.
+    }
+
+    /// Distance of both gutters' centers from the center line.
+    pub fn gutter_y(&self) -> f32 {
+        self.half_size.y - GutterPlugin::HEIGHT / 2.
+    }
+}
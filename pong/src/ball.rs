@@ -6,7 +6,9 @@ use bevy::{
     sprite::MaterialMesh2dBundle,
 };
 
-use crate::{Collision, Position, Shape, Velocity};
+use heep::{PhysicsSet, Position, Shape, SpatialIndex, Velocity};
+
+use crate::Collision;
 
 #[derive(Component)]
 pub struct Ball;
@@ -35,12 +37,12 @@ pub struct BallPlugin;
 impl Plugin for BallPlugin {
     fn build(&self, app: &mut App) {
         app.add_systems(Startup, Self::spawn_ball);
-        app.add_systems(Update, (Self::handle_collisions, Self::move_ball).chain());
+        app.add_systems(FixedUpdate, Self::handle_collisions.before(PhysicsSet));
     }
 }
 
 impl BallPlugin {
-    const SIZE: f32 = 5.;
+    pub const SIZE: f32 = 5.;
 
     fn spawn_ball(
         mut commands: Commands,
@@ -57,7 +59,7 @@ impl BallPlugin {
 
         commands.spawn((
             BallBundle::new(
-                Velocity(Vec2::new(1., 2.)),
+                Velocity(Vec2::new(60., 120.)),
                 Shape(Vec2::new(Self::SIZE, Self::SIZE)),
             ),
             MaterialMesh2dBundle {
@@ -68,12 +70,6 @@ impl BallPlugin {
         ));
     }
 
-    pub fn move_ball(mut ball: Query<(&mut Position, &Velocity), With<Ball>>) {
-        if let Ok((mut position, velocity)) = ball.get_single_mut() {
-            position.0 += velocity.0;
-        }
-    }
-
     pub fn collide_with_side(ball: BoundingCircle, wall: Aabb2d) -> Option<Collision> {
         if !ball.intersects(&wall) {
             return None;
@@ -97,21 +93,33 @@ impl BallPlugin {
         Some(side)
     }
 
+    /// Velocity of the ball after hitting `wall`, flipped along the axis of
+    /// the side it hit. Unchanged if they don't touch.
+    pub fn bounce(velocity: Vec2, ball: BoundingCircle, wall: Aabb2d) -> Vec2 {
+        match Self::collide_with_side(ball, wall) {
+            Some(Collision::Left | Collision::Right) => Vec2::new(-velocity.x, velocity.y),
+            Some(Collision::Top | Collision::Bottom) => Vec2::new(velocity.x, -velocity.y),
+            None => velocity,
+        }
+    }
+
     pub fn handle_collisions(
+        index: Res<SpatialIndex>,
         mut ball: Query<(&mut Velocity, &Position, &Shape), With<Ball>>,
         others: Query<(&Position, &Shape), Without<Ball>>,
     ) {
         if let Ok((mut ball_velocity, ball_position, ball_shape)) = ball.get_single_mut() {
-            for (position, shape) in &others {
-                if let Some(collision) = Self::collide_with_side(
+            let nearby =
+                index.query_aabb(Rect::from_center_half_size(ball_position.0, ball_shape.0));
+            for (position, shape) in nearby
+                .iter()
+                .filter_map(|entry| others.get(entry.entity).ok())
+            {
+                ball_velocity.0 = Self::bounce(
+                    ball_velocity.0,
                     BoundingCircle::new(ball_position.0, ball_shape.0.x),
                     Aabb2d::new(position.0, shape.0 / 2.),
-                ) {
-                    match collision {
-                        Collision::Left | Collision::Right => ball_velocity.0.x *= -1.0,
-                        Collision::Top | Collision::Bottom => ball_velocity.0.y *= -1.0,
-                    }
-                }
+                );
             }
         }
     }
@@ -1,6 +1,8 @@
 use bevy::{prelude::*, sprite::MaterialMesh2dBundle};
 
-use crate::{Position, Reference, Shape};
+use heep::{Position, Shape};
+
+use crate::arena::Arena;
 
 #[derive(Component)]
 pub struct Gutter;
@@ -31,7 +33,7 @@ impl Plugin for GutterPlugin {
 }
 
 impl GutterPlugin {
-    const HEIGHT: f32 = 20.;
+    pub const HEIGHT: f32 = 20.;
 
     fn spawn_gutters(
         mut commands: Commands,
@@ -40,15 +42,12 @@ impl GutterPlugin {
         window: Query<&Window>,
     ) {
         if let Ok(window) = window.get_single() {
-            let window_width = window.resolution.width();
-            let window_height = window.resolution.height();
-
-            let top_gutter_y = window_height / 2. - Self::HEIGHT / 2.;
-            let bottom_gutter_y = -window_height / 2. + Self::HEIGHT / 2.;
-            let gutter_shape = Shape(Vec2::new(window_width, Self::HEIGHT));
+            let arena = Arena::new(window);
+            let top_gutter_y = arena.gutter_y();
+            let bottom_gutter_y = -arena.gutter_y();
+            let gutter_shape = Shape(Vec2::new(arena.half_size.x * 2., Self::HEIGHT));
 
-            let top_gutter =
-                GutterBundle::new(Position(Vec2::new(0., top_gutter_y)), gutter_shape.clone());
+            let top_gutter = GutterBundle::new(Position(Vec2::new(0., top_gutter_y)), gutter_shape);
             let bottom_gutter =
                 GutterBundle::new(Position(Vec2::new(0., bottom_gutter_y)), gutter_shape);
 
@@ -59,7 +58,6 @@ impl GutterPlugin {
             let material_handle = materials.add(material);
 
             commands.spawn((
-                Reference,
                 top_gutter,
                 MaterialMesh2dBundle {
                     mesh: mesh_handle.clone().into(),
@@ -0,0 +1,21 @@
+mod arena;
+mod ball;
+mod gutter;
+mod neuro;
+mod paddle;
+mod scorer;
+
+pub use arena::*;
+pub use ball::*;
+pub use gutter::*;
+pub use neuro::*;
+pub use paddle::*;
+pub use scorer::*;
+
+#[derive(Debug)]
+pub enum Collision {
+    Left,
+    Right,
+    Top,
+    Bottom,
+}
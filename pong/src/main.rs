@@ -1,35 +1,33 @@
-use ball::BallPlugin;
-use bevy::{log::LogPlugin, prelude::*};
-use gutter::GutterPlugin;
-use paddle::PaddlePlugin;
-use scorer::ScorePlugin;
-
-mod ball;
-mod gutter;
-mod paddle;
-mod scorer;
-
-#[derive(Component)]
-pub struct Position(Vec2);
-
-#[derive(Component)]
-pub struct Velocity(Vec2);
+use std::{fs, path::Path};
 
-#[derive(Component, Clone, Copy)]
-pub struct Shape(Vec2);
-
-#[derive(Component)]
-pub struct Reference;
-
-#[derive(Debug)]
-enum Collision {
-    Left,
-    Right,
-    Top,
-    Bottom,
-}
+use bevy::{log::LogPlugin, prelude::*};
+use heep::{PhysicsPlugin, ProjectionPlugin, SpatialIndex, SpatialIndexPlugin};
+use pong::{BallPlugin, GutterPlugin, PaddlePlugin, ScorePlugin, NETWORK_ASSET};
 
 fn main() {
+    // `--train [generations] [seed]` evolves the neural AI headlessly instead
+    // of playing, and saves it into this crate's assets.
+    let mut args = std::env::args().skip(1);
+    if args.next().as_deref() == Some("--train") {
+        let generations = args.next().and_then(|n| n.parse().ok()).unwrap_or(100);
+        let seed = args.next().and_then(|n| n.parse().ok());
+        let network = pong::train(generations, seed, |generation, statistics| {
+            println!(
+                "generation {generation}: best {:.1}, mean {:.1}",
+                statistics.best, statistics.mean
+            );
+        });
+
+        let path = Path::new(env!("CARGO_MANIFEST_DIR"))
+            .join("assets")
+            .join(NETWORK_ASSET);
+        match fs::write(&path, network.to_string()) {
+            Ok(()) => println!("saved the best network to {}", path.display()),
+            Err(error) => eprintln!("could not save the network: {error}"),
+        }
+        return;
+    }
+
     let mut app = App::new();
     #[cfg(debug_assertions)]
     let log = LogPlugin {
@@ -44,12 +42,13 @@ fn main() {
     };
 
     app.add_plugins(DefaultPlugins.set(log));
+    app.insert_resource(SpatialIndex::new(50.));
+    app.add_plugins((PhysicsPlugin, ProjectionPlugin, SpatialIndexPlugin));
     app.add_systems(Startup, spawn_camera);
     app.add_plugins(BallPlugin);
     app.add_plugins(GutterPlugin);
     app.add_plugins(PaddlePlugin);
     app.add_plugins(ScorePlugin);
-    app.add_systems(PostUpdate, project_positions);
     app.run();
 }
 
@@ -57,9 +56,3 @@ fn spawn_camera(mut commands: Commands) {
     debug!("spawning camera");
     commands.spawn(Camera2dBundle::default());
 }
-
-fn project_positions(mut positionables: Query<(&mut Transform, &Position)>) {
-    for (mut transform, position) in &mut positionables {
-        transform.translation = position.0.extend(0.);
-    }
-}
@@ -0,0 +1,309 @@
+use std::{fmt, io, thread};
+
+use bevy::{
+    asset::{io::Reader, AssetLoader, AsyncReadExt, LoadContext},
+    math::bounding::{Aabb2d, BoundingCircle},
+    prelude::*,
+};
+use bevy_turborand::{rng::Rng, SeededCore, TurboRand};
+use heep::{Activation, Genome, Network, NetworkError, Population, Selection, Statistics};
+
+use crate::{arena::Arena, ball::BallPlugin, gutter::GutterPlugin, paddle::PaddlePlugin};
+
+/// Asset the game loads the neural AI from. `--train` writes it into the
+/// crate's `assets` folder.
+pub const NETWORK_ASSET: &str = "neural_ai.nn";
+
+/// Network that reads the ball position and velocity and the paddle height
+/// and outputs the paddle's vertical velocity as a fraction of its speed.
+pub fn network() -> Network {
+    Network::new(5)
+        .with_layer(6, Activation::Tanh)
+        .with_layer(1, Activation::Tanh)
+}
+
+/// Inputs for [`network`], scaled to roughly `[-1, 1]` by the size of the
+/// `arena`.
+pub fn inputs(arena: &Arena, ball: Vec2, ball_velocity: Vec2, paddle_y: f32) -> [f32; 5] {
+    [
+        ball.x / arena.half_size.x,
+        ball.y / arena.half_size.y,
+        ball_velocity.x / PaddlePlugin::SPEED,
+        ball_velocity.y / PaddlePlugin::SPEED,
+        paddle_y / arena.half_size.y,
+    ]
+}
+
+/// Vertical velocity `network` gives the left paddle.
+pub fn paddle_velocity(
+    network: &Network,
+    arena: &Arena,
+    ball: Vec2,
+    ball_velocity: Vec2,
+    paddle_y: f32,
+) -> f32 {
+    let input = inputs(arena, ball, ball_velocity, paddle_y);
+    network.forward(&input)[0].clamp(-1., 1.) * PaddlePlugin::SPEED
+}
+
+/// A trained [`network`], loaded from a `.nn` file.
+#[derive(Asset, TypePath, Clone, Debug)]
+pub struct NeuralNetwork(pub Network);
+
+#[derive(Debug)]
+pub enum NeuralNetworkError {
+    Io(io::Error),
+    Parse(NetworkError),
+}
+
+impl fmt::Display for NeuralNetworkError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Io(error) => write!(f, "could not read network: {error}"),
+            Self::Parse(error) => write!(f, "could not parse network: {error}"),
+        }
+    }
+}
+
+impl std::error::Error for NeuralNetworkError {}
+
+#[derive(Default)]
+pub struct NeuralNetworkLoader;
+
+impl AssetLoader for NeuralNetworkLoader {
+    type Asset = NeuralNetwork;
+    type Settings = ();
+    type Error = NeuralNetworkError;
+
+    async fn load<'a>(
+        &'a self,
+        reader: &'a mut Reader<'_>,
+        _settings: &'a (),
+        _load_context: &'a mut LoadContext<'_>,
+    ) -> Result<NeuralNetwork, NeuralNetworkError> {
+        let mut source = String::new();
+        reader
+            .read_to_string(&mut source)
+            .await
+            .map_err(NeuralNetworkError::Io)?;
+        source
+            .parse()
+            .map(NeuralNetwork)
+            .map_err(NeuralNetworkError::Parse)
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &["nn"]
+    }
+}
+
+/// Weights and biases of a [`network`].
+#[derive(Clone)]
+struct Brain(Vec<f32>);
+
+impl Brain {
+    fn network(&self) -> Network {
+        let mut network = network();
+        network
+            .set_parameters(&self.0)
+            .expect("brain matches the network's shape");
+        network
+    }
+}
+
+impl Genome for Brain {
+    type Config = usize;
+
+    fn random(parameters: &usize, rng: &impl TurboRand) -> Self {
+        Self((0..*parameters).map(|_| rng.f32_normalized()).collect())
+    }
+
+    fn crossover(&self, other: &Self, rng: &impl TurboRand) -> Self {
+        Self(
+            self.0
+                .iter()
+                .zip(&other.0)
+                .map(|(a, b)| if rng.bool() { *a } else { *b })
+                .collect(),
+        )
+    }
+
+    /// Nudges weights rather than replacing them, so good brains drift
+    /// instead of forgetting.
+    fn mutate(&mut self, rate: f32, rng: &impl TurboRand) {
+        for weight in &mut self.0 {
+            if rng.f32() < rate {
+                *weight += rng.f32_normalized() * 0.5;
+            }
+        }
+    }
+}
+
+/// A headless rally between a network on the left and the classic AI on
+/// the right, in the default [`Arena`] with the game's collisions.
+pub struct Match {
+    pub ball: Vec2,
+    pub ball_velocity: Vec2,
+    /// Heights of the left and right paddles.
+    pub left: f32,
+    pub right: f32,
+    /// Balls the network returned across the center line.
+    pub hits: u32,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Point {
+    Won,
+    Lost,
+    Timeout,
+}
+
+impl Match {
+    /// The game's default fixed timestep.
+    pub const DT: f32 = 1. / 64.;
+    const MAX_SECONDS: f32 = 60.;
+
+    pub fn new(ball_velocity: Vec2) -> Self {
+        Self {
+            ball: Vec2::ZERO,
+            ball_velocity,
+            left: 0.,
+            right: 0.,
+            hits: 0,
+        }
+    }
+
+    /// Plays until a point is scored or a minute has passed.
+    pub fn play(&mut self, network: &Network) -> Point {
+        (0..(Self::MAX_SECONDS / Self::DT) as usize)
+            .find_map(|_| self.step(network))
+            .unwrap_or(Point::Timeout)
+    }
+
+    /// Mirrors a fixed step of the game: paddles steer, the ball bounces off
+    /// what it touches, everything moves and the paddles are kept in bounds.
+    /// Returns the point once the ball leaves the arena.
+    pub fn step(&mut self, network: &Network) -> Option<Point> {
+        let arena = Arena::DEFAULT;
+        let max_y = arena.max_paddle_y();
+        let paddle_half_size = Vec2::new(PaddlePlugin::WIDTH, PaddlePlugin::HEIGHT) / 2.;
+        let gutter_half_size = Vec2::new(arena.half_size.x, GutterPlugin::HEIGHT / 2.);
+
+        let left_velocity =
+            paddle_velocity(network, &arena, self.ball, self.ball_velocity, self.left);
+        let right_velocity = PaddlePlugin::classic_ai_velocity(self.ball.y, self.right);
+
+        let ball = BoundingCircle::new(self.ball, BallPlugin::SIZE);
+        let walls = [
+            (Vec2::new(0., arena.gutter_y()), gutter_half_size),
+            (Vec2::new(0., -arena.gutter_y()), gutter_half_size),
+            (Vec2::new(arena.paddle_x(), self.right), paddle_half_size),
+        ];
+        for (center, half_size) in walls {
+            self.ball_velocity =
+                BallPlugin::bounce(self.ball_velocity, ball, Aabb2d::new(center, half_size));
+        }
+        let left = Aabb2d::new(Vec2::new(-arena.paddle_x(), self.left), paddle_half_size);
+        self.ball_velocity = BallPlugin::bounce(self.ball_velocity, ball, left);
+
+        self.left = (self.left + left_velocity * Self::DT).clamp(-max_y, max_y);
+        self.right = (self.right + right_velocity * Self::DT).clamp(-max_y, max_y);
+        let previous_x = self.ball.x;
+        self.ball += self.ball_velocity * Self::DT;
+
+        // Count returns as the ball crosses back over the center line, a
+        // ball caught bouncing against the paddle doesn't count.
+        if previous_x < 0. && self.ball.x >= 0. {
+            self.hits += 1;
+        }
+        if self.ball.x < -arena.half_size.x {
+            return Some(Point::Lost);
+        }
+        if self.ball.x > arena.half_size.x {
+            return Some(Point::Won);
+        }
+        None
+    }
+}
+
+/// Plays a match for each serve and scores one point per returned ball and
+/// ten per point won.
+fn fitness(brain: &Brain, serves: &[Vec2]) -> f32 {
+    let network = brain.network();
+    serves
+        .iter()
+        .map(|&serve| {
+            let mut rally = Match::new(serve);
+            let won = match rally.play(&network) {
+                Point::Won => 10.,
+                Point::Lost | Point::Timeout => 0.,
+            };
+            rally.hits as f32 + won
+        })
+        .sum()
+}
+
+/// Evolves a population of networks over `generations`, scoring each
+/// generation's matches across every available core, and returns the best.
+/// `report` is called with each generation's statistics. The same `seed`
+/// evolves the same network.
+pub fn train(
+    generations: usize,
+    seed: Option<u64>,
+    mut report: impl FnMut(usize, &Statistics),
+) -> Network {
+    const POPULATION: usize = 200;
+    const SERVES: usize = 8;
+
+    let rng = seed.map_or_else(Rng::new, Rng::with_seed);
+    let mut population =
+        Population::<Brain>::random(POPULATION, &network().parameter_count(), &rng)
+            .with_selection(Selection::Tournament { size: 5 })
+            .with_elitism(4)
+            .with_mutation_rate(0.1);
+    let threads = thread::available_parallelism().map_or(4, usize::from);
+
+    for generation in 0..generations {
+        // Fresh serves every generation so brains can't overfit to a few rallies.
+        let serves: Vec<_> = (0..SERVES)
+            .map(|i| {
+                let x = if i % 2 == 0 { -60. } else { 60. };
+                Vec2::new(x, rng.f32_normalized() * 120.)
+            })
+            .collect();
+
+        let members = &population.members;
+        let chunk = members.len().div_ceil(threads);
+        let scores: Vec<f32> = thread::scope(|scope| {
+            let handles: Vec<_> = members
+                .chunks(chunk)
+                .map(|brains| {
+                    let serves = &serves;
+                    scope.spawn(move || {
+                        brains
+                            .iter()
+                            .map(|brain| fitness(brain, serves))
+                            .collect::<Vec<_>>()
+                    })
+                })
+                .collect();
+            handles
+                .into_iter()
+                .flat_map(|handle| handle.join().unwrap())
+                .collect()
+        });
+
+        let statistics = population.set_fitness(scores);
+        report(generation, &statistics);
+
+        if generation + 1 < generations {
+            population.evolve(&rng);
+        }
+    }
+
+    population
+        .best()
+        .expect("population is not empty")
+        .0
+        .network()
+}
@@ -1,6 +1,12 @@
 use bevy::{prelude::*, sprite::MaterialMesh2dBundle};
 
-use crate::{ball::Ball, gutter::Gutter, Position, Reference, Shape, Velocity};
+use heep::{PhysicsSet, Position, Shape, Velocity};
+
+use crate::{
+    arena::Arena,
+    ball::Ball,
+    neuro::{self, NeuralNetwork, NeuralNetworkLoader},
+};
 
 #[derive(Component)]
 pub struct Paddle;
@@ -11,6 +17,20 @@ pub struct Player;
 #[derive(Component)]
 pub struct Ai;
 
+/// How the left paddle is controlled. `N` switches to the neural AI once a
+/// trained network has loaded from [`neuro::NETWORK_ASSET`].
+#[derive(Resource, Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum AiDifficulty {
+    /// Chases the ball's height at slightly less than full speed.
+    #[default]
+    Classic,
+    /// Driven by the network evolved with `--train`.
+    Neural,
+}
+
+#[derive(Resource)]
+struct NeuralAi(Handle<NeuralNetwork>);
+
 #[derive(Bundle)]
 struct PaddleBundle {
     paddle: Paddle,
@@ -34,22 +54,33 @@ pub struct PaddlePlugin;
 
 impl Plugin for PaddlePlugin {
     fn build(&self, app: &mut App) {
-        app.add_systems(Startup, Self::spawn_paddles);
+        app.init_asset::<NeuralNetwork>();
+        app.init_asset_loader::<NeuralNetworkLoader>();
+        app.init_resource::<AiDifficulty>();
+        app.add_systems(Startup, (Self::spawn_paddles, Self::load_network));
         app.add_systems(
             Update,
             (
-                Self::move_paddles,
-                Self::handle_ai.after(Self::move_paddles),
-                Self::handle_player_input.after(Self::move_paddles),
+                (Self::select_difficulty, Self::handle_ai).chain(),
+                Self::handle_player_input,
             ),
         );
+        app.add_systems(FixedUpdate, Self::constrain_paddles.after(PhysicsSet));
     }
 }
 
 impl PaddlePlugin {
-    const SPEED: f32 = 2.;
-    const WIDTH: f32 = 10.;
-    const HEIGHT: f32 = 50.;
+    pub const SPEED: f32 = 120.;
+    pub const WIDTH: f32 = 10.;
+    pub const HEIGHT: f32 = 50.;
+    /// Gap between each paddle and its side of the window.
+    pub const PADDING: f32 = 50.;
+
+    /// Vertical velocity of the classic AI, chasing the ball's height at
+    /// slightly less than full speed.
+    pub fn classic_ai_velocity(ball_y: f32, paddle_y: f32) -> f32 {
+        (ball_y - paddle_y).signum() * Self::SPEED * 0.9
+    }
 
     fn spawn_paddles(
         mut commands: Commands,
@@ -60,10 +91,8 @@ impl PaddlePlugin {
         debug!("spawining paddles");
 
         if let Ok(window) = window.get_single() {
-            let window_width = window.resolution.width();
-            let padding = 50.;
-            let right_paddle_x = window_width / 2. - padding;
-            let left_paddle_x = -window_width / 2. + padding;
+            let paddle_x = Arena::new(window).paddle_x();
+            let (left_paddle_x, right_paddle_x) = (-paddle_x, paddle_x);
 
             let mesh = Mesh::from(Rectangle::new(Self::WIDTH, Self::HEIGHT));
             let mesh_handle = meshes.add(mesh);
@@ -102,41 +131,72 @@ impl PaddlePlugin {
     ) {
         if let Ok(mut velocity) = paddle.get_single_mut() {
             if keyboard_input.pressed(KeyCode::ArrowUp) {
-                velocity.0.y = 1.;
+                velocity.0.y = Self::SPEED;
             } else if keyboard_input.pressed(KeyCode::ArrowDown) {
-                velocity.0.y = -1.;
+                velocity.0.y = -Self::SPEED;
             } else {
                 velocity.0.y = 0.;
             }
         }
     }
 
+    fn load_network(mut commands: Commands, asset_server: Res<AssetServer>) {
+        commands.insert_resource(NeuralAi(asset_server.load(neuro::NETWORK_ASSET)));
+    }
+
+    fn select_difficulty(
+        keyboard_input: Res<ButtonInput<KeyCode>>,
+        neural: Res<NeuralAi>,
+        networks: Res<Assets<NeuralNetwork>>,
+        mut difficulty: ResMut<AiDifficulty>,
+    ) {
+        if keyboard_input.just_pressed(KeyCode::KeyN) {
+            let loaded = networks.contains(&neural.0);
+            if !loaded {
+                info!(
+                    "no neural AI loaded from {}, run with --train to evolve one",
+                    neuro::NETWORK_ASSET
+                );
+            }
+            *difficulty = match *difficulty {
+                AiDifficulty::Classic if loaded => AiDifficulty::Neural,
+                _ => AiDifficulty::Classic,
+            };
+            debug!("AI difficulty: {:?}", *difficulty);
+        }
+    }
+
+    #[allow(clippy::type_complexity)]
     fn handle_ai(
-        mut paddle: Query<(&mut Velocity, &Position), With<Ai>>,
-        ball: Query<&Position, With<Ball>>,
+        difficulty: Res<AiDifficulty>,
+        neural: Res<NeuralAi>,
+        networks: Res<Assets<NeuralNetwork>>,
+        window: Query<&Window>,
+        mut paddle: Query<(&mut Velocity, &Position), (With<Ai>, Without<Ball>)>,
+        ball: Query<(&Position, &Velocity), With<Ball>>,
     ) {
-        if let (Ok((mut velocity, position)), Ok(ball_position)) =
+        if let (Ok((mut velocity, position)), Ok((ball_position, ball_velocity))) =
             (paddle.get_single_mut(), ball.get_single())
         {
-            let a_to_b = ball_position.0 - position.0;
-            velocity.0.y = a_to_b.y.signum() * 0.9;
+            velocity.0.y = match (*difficulty, networks.get(&neural.0), window.get_single()) {
+                (AiDifficulty::Neural, Some(network), Ok(window)) => neuro::paddle_velocity(
+                    &network.0,
+                    &Arena::new(window),
+                    ball_position.0,
+                    ball_velocity.0,
+                    position.0.y,
+                ),
+                _ => Self::classic_ai_velocity(ball_position.0.y, position.0.y),
+            };
         }
     }
 
-    fn move_paddles(
-        mut paddles: Query<(&mut Position, &Velocity), With<Paddle>>,
-        gutter_shape: Query<&Shape, (With<Gutter>, With<Reference>)>,
-        window: Query<&Window>,
-    ) {
-        if let (Ok(window), Ok(gutter)) = (window.get_single(), gutter_shape.get_single()) {
-            let window_height = window.resolution.height();
-            let max_y = window_height / 2. - gutter.0.y - Self::HEIGHT / 2.;
-
-            for (mut position, velocity) in &mut paddles {
-                let new_position = position.0 + velocity.0 * Self::SPEED;
-                if new_position.y.abs() < max_y {
-                    position.0 = new_position;
-                }
+    fn constrain_paddles(mut paddles: Query<&mut Position, With<Paddle>>, window: Query<&Window>) {
+        if let Ok(window) = window.get_single() {
+            let max_y = Arena::new(window).max_paddle_y();
+
+            for mut position in &mut paddles {
+                position.0.y = position.0.y.clamp(-max_y, max_y);
             }
         }
     }
@@ -1,6 +1,8 @@
 use bevy::prelude::*;
 
-use crate::{ball::Ball, Position, Velocity};
+use heep::{Placement, Position, Velocity};
+
+use crate::ball::Ball;
 
 enum Scorer {
     Ai,
@@ -115,15 +117,15 @@ impl ScorePlugin {
     }
 
     fn reset_ball(
-        mut ball: Query<(&mut Position, &mut Velocity), With<Ball>>,
+        mut ball: Query<(Placement, &mut Velocity), With<Ball>>,
         mut events: EventReader<Scored>,
     ) {
         for event in events.read() {
-            if let Ok((mut position, mut velocity)) = ball.get_single_mut() {
-                position.0 = Vec2::ZERO;
+            if let Ok((mut placement, mut velocity)) = ball.get_single_mut() {
+                placement.teleport(Vec2::ZERO);
                 match event.0 {
-                    Scorer::Ai => velocity.0 = Vec2::new(-1., 2.),
-                    Scorer::Player => velocity.0 = Vec2::new(1., 2.),
+                    Scorer::Ai => velocity.0 = Vec2::new(-60., 120.),
+                    Scorer::Player => velocity.0 = Vec2::new(60., 120.),
                 }
             }
         }
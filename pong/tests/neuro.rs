@@ -0,0 +1,163 @@
+use std::time::Duration;
+
+use bevy::{
+    prelude::*,
+    time::{TimePlugin, TimeUpdateStrategy},
+};
+use bevy_turborand::{rng::Rng, SeededCore};
+use heep::{
+    Network, PhysicsPlugin, PhysicsSet, Position, Shape, SpatialIndex, SpatialIndexPlugin, Velocity,
+};
+use pong::{Arena, Ball, BallPlugin, GutterPlugin, Match, Paddle, PaddlePlugin, Point};
+
+#[derive(Component)]
+struct Left;
+
+#[derive(Component)]
+struct Right;
+
+#[derive(Resource)]
+struct Brain(Network);
+
+/// Steers the paddles the way `Match` does: the network on the left and the
+/// classic AI on the right.
+#[allow(clippy::type_complexity)]
+fn steer(
+    brain: Res<Brain>,
+    ball: Query<(&Position, &Velocity), With<Ball>>,
+    mut left: Query<(&Position, &mut Velocity), (With<Left>, Without<Ball>)>,
+    mut right: Query<(&Position, &mut Velocity), (With<Right>, Without<Ball>, Without<Left>)>,
+) {
+    let (ball, ball_velocity) = ball.single();
+    let (position, mut velocity) = left.single_mut();
+    velocity.0.y = pong::paddle_velocity(
+        &brain.0,
+        &Arena::DEFAULT,
+        ball.0,
+        ball_velocity.0,
+        position.0.y,
+    );
+    let (position, mut velocity) = right.single_mut();
+    velocity.0.y = PaddlePlugin::classic_ai_velocity(ball.0.y, position.0.y);
+}
+
+fn constrain(mut paddles: Query<&mut Position, With<Paddle>>) {
+    let max_y = Arena::DEFAULT.max_paddle_y();
+    for mut position in &mut paddles {
+        position.0.y = position.0.y.clamp(-max_y, max_y);
+    }
+}
+
+/// The game's collisions and physics in the default arena, without windows
+/// or rendering.
+fn app(network: Network, serve: Vec2) -> App {
+    let step = Duration::from_secs_f32(Match::DT);
+    let arena = Arena::DEFAULT;
+    let mut app = App::new();
+    app.add_plugins((TimePlugin, PhysicsPlugin, SpatialIndexPlugin))
+        .insert_resource(SpatialIndex::new(50.))
+        .insert_resource(Brain(network))
+        .insert_resource(Time::<Fixed>::from_duration(step))
+        .insert_resource(TimeUpdateStrategy::ManualDuration(step))
+        .add_systems(
+            FixedUpdate,
+            (
+                steer.before(BallPlugin::handle_collisions),
+                BallPlugin::handle_collisions.before(PhysicsSet),
+                constrain.after(PhysicsSet),
+            ),
+        );
+
+    let world = app.world_mut();
+    world.spawn((
+        Ball,
+        Position(Vec2::ZERO),
+        Velocity(serve),
+        Shape(Vec2::splat(BallPlugin::SIZE)),
+    ));
+    for y in [arena.gutter_y(), -arena.gutter_y()] {
+        world.spawn((
+            Position(Vec2::new(0., y)),
+            Shape(Vec2::new(arena.half_size.x * 2., GutterPlugin::HEIGHT)),
+        ));
+    }
+    let paddle = Shape(Vec2::new(PaddlePlugin::WIDTH, PaddlePlugin::HEIGHT));
+    world.spawn((
+        Left,
+        Paddle,
+        Position(Vec2::new(-arena.paddle_x(), 0.)),
+        Velocity(Vec2::ZERO),
+        paddle,
+    ));
+    world.spawn((
+        Right,
+        Paddle,
+        Position(Vec2::new(arena.paddle_x(), 0.)),
+        Velocity(Vec2::ZERO),
+        paddle,
+    ));
+
+    // The first update only starts the clock.
+    app.update();
+    app
+}
+
+/// Plays `serve` both headlessly and through the game's systems and checks
+/// the ball follows the same path until the point ends. Returns the point
+/// and how often the ball bounced off a paddle and off a gutter.
+fn assert_same_rally(network: Network, serve: Vec2) -> (Point, UVec2) {
+    let mut app = app(network.clone(), serve);
+    let mut rally = Match::new(serve);
+    let mut bounces = UVec2::ZERO;
+
+    for step in 0..10_000 {
+        let before = rally.ball_velocity;
+        let point = rally.step(&network);
+        bounces += UVec2::new(
+            (rally.ball_velocity.x != before.x) as u32,
+            (rally.ball_velocity.y != before.y) as u32,
+        );
+
+        app.update();
+        let ball = app
+            .world_mut()
+            .query_filtered::<&Position, With<Ball>>()
+            .single(app.world())
+            .0;
+        assert!(
+            ball.distance(rally.ball) < 1e-3,
+            "step {step}: game at {ball}, match at {}",
+            rally.ball
+        );
+
+        if let Some(point) = point {
+            return (point, bounces);
+        }
+    }
+    panic!("the rally never ended");
+}
+
+#[test]
+fn match_follows_the_game_toward_the_network() {
+    // An untrained network leaves the paddle still, the ball bounces off the
+    // gutters on its way past.
+    let (point, bounces) = assert_same_rally(pong::network(), Vec2::new(-150., 200.));
+    assert_eq!(point, Point::Lost);
+    assert_eq!(bounces.x, 0);
+    assert!(bounces.y > 0);
+}
+
+#[test]
+fn match_follows_the_game_toward_the_classic_ai() {
+    let network = pong::network().randomized(&Rng::with_seed(3));
+    let (_, bounces) = assert_same_rally(network, Vec2::new(150., -90.));
+    assert!(bounces.x > 0);
+    assert!(bounces.y > 0);
+}
+
+#[test]
+fn seeded_training_is_reproducible() {
+    let first = pong::train(1, Some(7), |_, _| {});
+    let second = pong::train(1, Some(7), |_, _| {});
+    assert_eq!(first.to_string(), second.to_string());
+}
@@ -0,0 +1,53 @@
+use std::f32::consts::TAU;
+
+use bevy::prelude::*;
+use bevy_turborand::TurboRand;
+use heep::Genome;
+
+/// One thrust per fixed step of a rocket's life.
+#[derive(Clone, Debug)]
+pub struct Dna {
+    pub genes: Vec<Vec2>,
+    pub max_force: f32,
+}
+
+/// Length of a [`Dna`] sequence and the strongest thrust it can hold.
+pub struct DnaConfig {
+    pub lifespan: usize,
+    pub max_force: f32,
+}
+
+impl Dna {
+    fn gene(&self, rng: &impl TurboRand) -> Vec2 {
+        Vec2::from_angle(rng.f32() * TAU) * rng.f32() * self.max_force
+    }
+}
+
+impl Genome for Dna {
+    type Config = DnaConfig;
+
+    fn random(config: &DnaConfig, rng: &impl TurboRand) -> Self {
+        let mut dna = Self {
+            genes: vec![Vec2::ZERO; config.lifespan],
+            max_force: config.max_force,
+        };
+        dna.mutate(1., rng);
+        dna
+    }
+
+    fn crossover(&self, other: &Self, rng: &impl TurboRand) -> Self {
+        let midpoint = rng.usize(..self.genes.len());
+        Self {
+            genes: [&self.genes[..midpoint], &other.genes[midpoint..]].concat(),
+            max_force: self.max_force,
+        }
+    }
+
+    fn mutate(&mut self, rate: f32, rng: &impl TurboRand) {
+        for index in 0..self.genes.len() {
+            if rng.f32() < rate {
+                self.genes[index] = self.gene(rng);
+            }
+        }
+    }
+}
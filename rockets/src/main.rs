@@ -0,0 +1,14 @@
+use bevy::prelude::*;
+use heep::Sketch;
+
+mod dna;
+mod rockets;
+
+fn main() {
+    App::new()
+        .add_plugins(
+            Sketch::new("heep - Smart Rockets").with_background(Color::srgb(0.1, 0.1, 0.15)),
+        )
+        .add_plugins(rockets::RocketPlugin)
+        .run();
+}
@@ -0,0 +1,277 @@
+use std::f32::consts::PI;
+
+use bevy::{prelude::*, sprite::MaterialMesh2dBundle};
+use bevy_turborand::{rng::Rng, DelegatedRng, GlobalRng, SeededCore};
+use heep::{
+    BodyBundle, FaceVelocity, Forces, Layer, PhysicsSet, Population, Position, Rotation, Selection,
+    Shape, Velocity, WorldBounds,
+};
+
+use crate::dna::{Dna, DnaConfig};
+
+#[derive(Clone, Copy, PartialEq)]
+enum Flight {
+    Flying,
+    Crashed,
+    /// Reached the target on the given step.
+    Landed(usize),
+}
+
+#[derive(Component)]
+struct Rocket {
+    /// Index of the rocket's [`Dna`] in the population.
+    index: usize,
+    flight: Flight,
+    closest: f32,
+}
+
+/// Kills any rocket that touches it.
+#[derive(Component)]
+struct Obstacle;
+
+#[derive(Component)]
+struct Hud;
+
+#[derive(Resource)]
+struct Generation(Population<Dna>);
+
+/// Fixed step within the current generation's lifespan.
+#[derive(Resource, Default)]
+struct Step(usize);
+
+#[derive(Resource)]
+struct RocketAssets {
+    mesh: Handle<Mesh>,
+    material: Handle<ColorMaterial>,
+}
+
+/// Evolves a population of rockets that fly a fixed sequence of thrusts
+/// each generation, trying to reach the target without hitting obstacles.
+pub struct RocketPlugin;
+
+impl Plugin for RocketPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<Step>();
+        app.add_systems(Startup, (Self::setup, Self::launch).chain());
+        app.add_systems(FixedUpdate, Self::thrust.before(PhysicsSet));
+        app.add_systems(
+            FixedUpdate,
+            (Self::check_flight, Self::next_generation)
+                .chain()
+                .after(PhysicsSet),
+        );
+        app.add_systems(Update, Self::update_hud);
+    }
+}
+
+impl RocketPlugin {
+    const POPULATION: usize = 200;
+    const LIFESPAN: usize = 400;
+    const MAX_FORCE: f32 = 40.;
+    const MUTATION_RATE: f32 = 0.01;
+    const ELITISM: usize = 2;
+    const LAUNCH: Vec2 = Vec2::new(0., -45.);
+    const TARGET: Vec2 = Vec2::new(0., 38.);
+    const TARGET_RADIUS: f32 = 3.;
+    const OBSTACLES: [(Vec2, Vec2); 2] = [
+        (Vec2::new(-15., 5.), Vec2::new(30., 1.5)),
+        (Vec2::new(35., -15.), Vec2::new(15., 1.5)),
+    ];
+    /// Crashing keeps this fraction of the fitness earned by getting close.
+    const CRASH_PENALTY: f32 = 0.1;
+
+    fn setup(
+        mut commands: Commands,
+        mut meshes: ResMut<Assets<Mesh>>,
+        mut materials: ResMut<Assets<ColorMaterial>>,
+        mut global_rng: ResMut<GlobalRng>,
+    ) {
+        let rng = Rng::with_seed(global_rng.u64(..));
+        let config = DnaConfig {
+            lifespan: Self::LIFESPAN,
+            max_force: Self::MAX_FORCE,
+        };
+        commands.insert_resource(Generation(
+            Population::random(Self::POPULATION, &config, &rng)
+                .with_selection(Selection::Roulette)
+                .with_elitism(Self::ELITISM)
+                .with_mutation_rate(Self::MUTATION_RATE),
+        ));
+
+        commands.insert_resource(RocketAssets {
+            mesh: meshes.add(Triangle2d::new(
+                Vec2::new(1.5, 0.),
+                Vec2::new(-1., 0.5),
+                Vec2::new(-1., -0.5),
+            )),
+            material: materials.add(Color::srgba(0.9, 0.9, 0.9, 0.6)),
+        });
+
+        commands.spawn(MaterialMesh2dBundle {
+            mesh: meshes
+                .add(Circle {
+                    radius: Self::TARGET_RADIUS,
+                })
+                .into(),
+            material: materials.add(Color::srgb(0.3, 0.8, 0.4)),
+            transform: Transform::from_translation(Self::TARGET.extend(-1.)),
+            ..Default::default()
+        });
+
+        let obstacle_material = materials.add(Color::srgb(0.8, 0.3, 0.3));
+        for (center, half_size) in Self::OBSTACLES {
+            commands.spawn((
+                MaterialMesh2dBundle {
+                    mesh: meshes.add(Rectangle::from_size(half_size * 2.)).into(),
+                    material: obstacle_material.clone(),
+                    ..Default::default()
+                },
+                Obstacle,
+                Position(center),
+                Shape(half_size),
+                Layer(-1.),
+            ));
+        }
+
+        commands.spawn((
+            TextBundle::from_section("", TextStyle::default()).with_style(Style {
+                position_type: PositionType::Absolute,
+                top: Val::Px(10.),
+                left: Val::Px(10.),
+                ..Default::default()
+            }),
+            Hud,
+        ));
+    }
+
+    fn launch(mut commands: Commands, assets: Res<RocketAssets>, generation: Res<Generation>) {
+        Self::spawn_rockets(&mut commands, &assets, generation.0.members.len());
+    }
+
+    fn spawn_rockets(commands: &mut Commands, assets: &RocketAssets, count: usize) {
+        for index in 0..count {
+            commands.spawn((
+                MaterialMesh2dBundle {
+                    mesh: assets.mesh.clone().into(),
+                    material: assets.material.clone(),
+                    ..Default::default()
+                },
+                Rocket {
+                    index,
+                    flight: Flight::Flying,
+                    closest: f32::INFINITY,
+                },
+                BodyBundle {
+                    position: Position(Self::LAUNCH),
+                    ..Default::default()
+                },
+                Rotation(PI / 2.),
+                FaceVelocity,
+            ));
+        }
+    }
+
+    fn thrust(
+        step: Res<Step>,
+        generation: Res<Generation>,
+        mut rockets: Query<(&Rocket, &mut Forces)>,
+    ) {
+        for (rocket, mut forces) in &mut rockets {
+            if rocket.flight == Flight::Flying {
+                let dna = &generation.0.members[rocket.index];
+                if let Some(&gene) = dna.genes.get(step.0) {
+                    forces.apply_force(gene);
+                }
+            }
+        }
+    }
+
+    #[allow(clippy::type_complexity)]
+    fn check_flight(
+        mut step: ResMut<Step>,
+        bounds: Res<WorldBounds>,
+        obstacles: Query<(&Position, &Shape), (With<Obstacle>, Without<Rocket>)>,
+        mut rockets: Query<(&mut Rocket, &mut Position, &mut Velocity)>,
+    ) {
+        for (mut rocket, mut position, mut velocity) in &mut rockets {
+            if rocket.flight != Flight::Flying {
+                continue;
+            }
+
+            let distance = position.0.distance(Self::TARGET);
+            rocket.closest = rocket.closest.min(distance);
+
+            let hit = obstacles.iter().any(|(center, half_size)| {
+                let offset = (position.0 - center.0).abs();
+                offset.x < half_size.0.x && offset.y < half_size.0.y
+            });
+            let escaped = !bounds.0.is_empty() && !bounds.0.contains(position.0);
+
+            if distance < Self::TARGET_RADIUS {
+                rocket.flight = Flight::Landed(step.0);
+                position.0 = Self::TARGET;
+            } else if hit || escaped {
+                rocket.flight = Flight::Crashed;
+            }
+            // Grounded rockets get no more thrust, so they stay where they stopped.
+            if rocket.flight != Flight::Flying {
+                velocity.0 = Vec2::ZERO;
+            }
+        }
+
+        step.0 += 1;
+    }
+
+    /// Closer is better, landing beats any near miss and landing sooner beats
+    /// landing later.
+    fn fitness(rocket: &Rocket) -> f32 {
+        let approach = 1. / (1. + rocket.closest);
+        match rocket.flight {
+            Flight::Landed(step) => 2. - step as f32 / Self::LIFESPAN as f32,
+            Flight::Crashed => approach * Self::CRASH_PENALTY,
+            Flight::Flying => approach,
+        }
+        .powi(2)
+    }
+
+    fn next_generation(
+        mut commands: Commands,
+        mut step: ResMut<Step>,
+        mut generation: ResMut<Generation>,
+        mut global_rng: ResMut<GlobalRng>,
+        assets: Res<RocketAssets>,
+        rockets: Query<(Entity, &Rocket)>,
+    ) {
+        let all_grounded = rockets.iter().all(|(_, r)| r.flight != Flight::Flying);
+        if step.0 < Self::LIFESPAN && !all_grounded {
+            return;
+        }
+
+        let mut fitness = vec![0.; generation.0.members.len()];
+        for (entity, rocket) in &rockets {
+            fitness[rocket.index] = Self::fitness(rocket);
+            commands.entity(entity).despawn();
+        }
+        generation.0.set_fitness(fitness);
+        generation.0.evolve(&Rng::with_seed(global_rng.u64(..)));
+        step.0 = 0;
+
+        Self::spawn_rockets(&mut commands, &assets, generation.0.members.len());
+    }
+
+    fn update_hud(generation: Res<Generation>, mut hud: Query<&mut Text, With<Hud>>) {
+        if !generation.is_changed() {
+            return;
+        }
+
+        let best = generation
+            .0
+            .history
+            .last()
+            .map_or(0., |statistics| statistics.best);
+        hud.single_mut().sections[0].value = format!(
+            "Generation {}\nBest fitness {best:.3}",
+            generation.0.generation
+        );
+    }
+}
@@ -1,84 +1,111 @@
-use bevy::{
-    prelude::*,
-    sprite::{MaterialMesh2dBundle, Mesh2dHandle},
-};
-use bevy_turborand::{DelegatedRng, RngComponent};
+use bevy::{prelude::*, sprite::MaterialMesh2dBundle};
+use bevy_turborand::{DelegatedRng, GlobalRng, RngComponent};
+use heep::{Emitter, Fbm, Layer, Perlin, Placement, Position, WorldBounds};
 
 #[derive(Component)]
-struct LastWalker;
+struct Walker;
 
 #[derive(Resource)]
-struct WalkerShape(Mesh2dHandle);
+struct StepTimer(Timer);
 
-#[derive(Resource)]
-struct WalkerColor(Handle<ColorMaterial>);
+/// How the walker picks its next step. Toggled with space.
+#[derive(Resource, Default, Clone, Copy, PartialEq, Eq)]
+pub enum WalkerMode {
+    /// Steps to a uniformly random neighbouring cell.
+    #[default]
+    Random,
+    /// Follows a smooth path through Perlin noise.
+    Noise,
+}
 
-#[derive(Resource)]
-struct SpawnTimer(Timer);
+/// Offset along the noise curve for [`WalkerMode::Noise`].
+#[derive(Resource, Default)]
+struct NoiseOffset(f32);
 
 pub struct WalkerPlugin;
 
 impl Plugin for WalkerPlugin {
     fn build(&self, app: &mut App) {
-        app.insert_resource(SpawnTimer(Timer::from_seconds(0.05, TimerMode::Repeating)));
+        app.insert_resource(StepTimer(Timer::from_seconds(0.05, TimerMode::Repeating)));
+        app.init_resource::<WalkerMode>();
+        app.init_resource::<NoiseOffset>();
         app.add_systems(Startup, Self::setup);
-        app.add_systems(Update, Self::update);
+        app.add_systems(Update, (Self::toggle_mode, Self::update).chain());
     }
 }
 
 impl WalkerPlugin {
     const COLOR: Color = Color::srgba(0.2, 0.2, 0.8, 0.6);
     const RADIUS: f32 = 1.5;
+    const TRAIL_RATE: f32 = 20.;
+    const TRAIL_LIFESPAN: f32 = 20.;
+    const NOISE_STEP: f32 = 0.01;
+    // Samples the second axis half a lattice cell past an integer so the
+    // axes stay uncorrelated, while small enough to keep f32 precision.
+    const NOISE_Y_OFFSET: f32 = 100.5;
 
     fn setup(
         mut commands: Commands,
         mut meshes: ResMut<Assets<Mesh>>,
         mut materials: ResMut<Assets<ColorMaterial>>,
     ) {
-        let shape = Mesh2dHandle(meshes.add(Circle {
-            radius: Self::RADIUS,
-        }));
-        let color = materials.add(Self::COLOR);
-
-        commands.insert_resource(WalkerShape(shape.clone()));
-        commands.insert_resource(WalkerColor(color.clone()));
-
         commands.spawn((
             MaterialMesh2dBundle {
-                mesh: shape,
-                material: color,
+                mesh: meshes
+                    .add(Circle {
+                        radius: Self::RADIUS,
+                    })
+                    .into(),
+                material: materials.add(Self::COLOR),
                 ..Default::default()
             },
-            LastWalker,
+            Walker,
+            Position::default(),
+            Layer(1.),
+            Emitter::new(Self::TRAIL_RATE, Self::TRAIL_LIFESPAN)
+                .with_color(Self::COLOR)
+                .with_size(Self::RADIUS * 2.),
         ));
     }
 
+    fn toggle_mode(keyboard_input: Res<ButtonInput<KeyCode>>, mut mode: ResMut<WalkerMode>) {
+        if keyboard_input.just_pressed(KeyCode::Space) {
+            *mode = match *mode {
+                WalkerMode::Random => WalkerMode::Noise,
+                WalkerMode::Noise => WalkerMode::Random,
+            };
+        }
+    }
+
+    #[allow(clippy::too_many_arguments)]
     fn update(
-        mut commands: Commands,
         time: Res<Time>,
-        mut timer: ResMut<SpawnTimer>,
-        shape: Res<WalkerShape>,
-        color: Res<WalkerColor>,
-        query: Query<(Entity, &Transform), With<LastWalker>>,
+        mut timer: ResMut<StepTimer>,
+        mut global_rng: ResMut<GlobalRng>,
+        mode: Res<WalkerMode>,
+        mut offset: ResMut<NoiseOffset>,
+        perlin: Res<Perlin>,
+        bounds: Res<WorldBounds>,
+        mut walker: Query<Placement, With<Walker>>,
     ) {
         if timer.0.tick(time.delta()).just_finished() {
-            let (entity, transform) = query.single();
-            commands.entity(entity).remove::<LastWalker>();
-
-            let mut rng = RngComponent::new();
-            let mut transform = transform.clone();
-            transform.translation.x += rng.i32(-1..=1) as f32 * Self::RADIUS * 2.;
-            transform.translation.y += rng.i32(-1..=1) as f32 * Self::RADIUS * 2.;
-
-            commands.spawn((
-                MaterialMesh2dBundle {
-                    mesh: shape.0.clone(),
-                    material: color.0.clone(),
-                    transform,
-                    ..Default::default()
-                },
-                LastWalker,
-            ));
+            let mut walker = walker.single_mut();
+            let position = match *mode {
+                WalkerMode::Random => {
+                    let mut rng = RngComponent::from(&mut global_rng);
+                    let step = Vec2::new(rng.i32(-1..=1) as f32, rng.i32(-1..=1) as f32);
+                    walker.position.0 + step * Self::RADIUS * 2.
+                }
+                WalkerMode::Noise => {
+                    offset.0 += Self::NOISE_STEP;
+                    let step = Vec2::new(
+                        perlin.fbm1(offset.0, Fbm::default()),
+                        perlin.fbm1(offset.0 + Self::NOISE_Y_OFFSET, Fbm::default()),
+                    );
+                    bounds.0.center() + step * bounds.0.half_size()
+                }
+            };
+            walker.teleport(position);
         }
     }
 }
@@ -0,0 +1,69 @@
+use bevy::prelude::*;
+
+/// What the cells beyond either end of the row read as.
+#[derive(Default, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Edges {
+    /// The row is a ring; the first and last cells are neighbours.
+    #[default]
+    Wrap,
+    /// Cells past the ends hold a constant state.
+    Fixed(bool),
+}
+
+/// A one dimensional, two state, radius one cellular automaton. The rule
+/// number's bits give the next state for each of the eight neighbourhoods,
+/// with `111` as the most significant bit.
+#[derive(Resource, Clone, Debug)]
+pub struct Elementary {
+    pub rule: u8,
+    pub edges: Edges,
+    cells: Vec<bool>,
+    generation: usize,
+}
+
+impl Elementary {
+    pub fn new(rule: u8, edges: Edges, cells: Vec<bool>) -> Self {
+        Self {
+            rule,
+            edges,
+            cells,
+            generation: 0,
+        }
+    }
+
+    /// A row of `width` dead cells with only the middle one alive.
+    pub fn single(rule: u8, edges: Edges, width: usize) -> Self {
+        let mut cells = vec![false; width];
+        if width > 0 {
+            cells[width / 2] = true;
+        }
+        Self::new(rule, edges, cells)
+    }
+
+    /// Next state of a cell given its neighbourhood under `rule`.
+    pub fn apply(rule: u8, left: bool, center: bool, right: bool) -> bool {
+        let neighbourhood = (left as u8) << 2 | (center as u8) << 1 | right as u8;
+        rule >> neighbourhood & 1 == 1
+    }
+
+    pub fn cells(&self) -> &[bool] {
+        &self.cells
+    }
+
+    pub fn generation(&self) -> usize {
+        self.generation
+    }
+
+    pub fn step(&mut self) {
+        let width = self.cells.len();
+        let cell = |index: isize| match self.edges {
+            Edges::Wrap => self.cells[index.rem_euclid(width as isize) as usize],
+            Edges::Fixed(state) => self.cells.get(index as usize).copied().unwrap_or(state),
+        };
+
+        self.cells = (0..width as isize)
+            .map(|i| Self::apply(self.rule, cell(i - 1), cell(i), cell(i + 1)))
+            .collect();
+        self.generation += 1;
+    }
+}
@@ -0,0 +1,203 @@
+use bevy::{
+    prelude::*,
+    render::{
+        render_asset::RenderAssetUsages,
+        render_resource::{Extent3d, TextureDimension, TextureFormat},
+        texture::ImageSampler,
+    },
+};
+use bevy_turborand::{DelegatedRng, GlobalRng};
+
+use crate::{Edges, Elementary};
+
+/// How each run of the automaton starts. Toggled with `S`.
+#[derive(Resource, Default, Clone, Copy, PartialEq, Eq)]
+pub enum InitialState {
+    #[default]
+    Single,
+    Random,
+}
+
+#[derive(Resource)]
+struct StepTimer(Timer);
+
+/// The texture generations are drawn into, one row of pixels each.
+#[derive(Resource)]
+struct Canvas(Handle<Image>);
+
+#[derive(Component)]
+struct RuleLabel;
+
+/// Runs an [`Elementary`] automaton and scrolls its history up the screen.
+///
+/// Left and right arrows step through the 256 rules, `S` switches between a
+/// single live cell and a random start, `E` cycles the edge handling and
+/// `R` restarts the current rule.
+pub struct ElementaryPlugin;
+
+impl Plugin for ElementaryPlugin {
+    fn build(&self, app: &mut App) {
+        app.insert_resource(StepTimer(Timer::from_seconds(
+            Self::STEP_SECONDS,
+            TimerMode::Repeating,
+        )));
+        app.init_resource::<InitialState>();
+        app.insert_resource(Elementary::single(Self::RULE, Edges::Wrap, Self::WIDTH));
+        app.add_systems(Startup, Self::setup);
+        app.add_systems(Update, (Self::controls, Self::update, Self::label).chain());
+    }
+}
+
+impl ElementaryPlugin {
+    pub const WIDTH: usize = 320;
+    pub const GENERATIONS: usize = 180;
+    const RULE: u8 = 30;
+    const STEP_SECONDS: f32 = 0.02;
+    const ALIVE: [u8; 4] = [240, 240, 220, 255];
+    const DEAD: [u8; 4] = [0, 0, 0, 255];
+
+    fn setup(mut commands: Commands, mut images: ResMut<Assets<Image>>) {
+        let mut image = Image::new_fill(
+            Extent3d {
+                width: Self::WIDTH as u32,
+                height: Self::GENERATIONS as u32,
+                depth_or_array_layers: 1,
+            },
+            TextureDimension::D2,
+            &Self::DEAD,
+            TextureFormat::Rgba8UnormSrgb,
+            RenderAssetUsages::default(),
+        );
+        image.sampler = ImageSampler::nearest();
+        let image = images.add(image);
+
+        commands.spawn(SpriteBundle {
+            texture: image.clone(),
+            sprite: Sprite {
+                custom_size: Some(Vec2::new(Self::WIDTH as f32, Self::GENERATIONS as f32)),
+                ..Default::default()
+            },
+            ..Default::default()
+        });
+        commands.insert_resource(Canvas(image));
+
+        commands.spawn((
+            TextBundle::from_section("", TextStyle::default()).with_style(Style {
+                position_type: PositionType::Absolute,
+                bottom: Val::Px(10.),
+                left: Val::Px(10.),
+                ..Default::default()
+            }),
+            RuleLabel,
+        ));
+    }
+
+    fn controls(
+        keyboard_input: Res<ButtonInput<KeyCode>>,
+        mut initial_state: ResMut<InitialState>,
+        mut automaton: ResMut<Elementary>,
+        mut rng: ResMut<GlobalRng>,
+        canvas: Res<Canvas>,
+        mut images: ResMut<Assets<Image>>,
+    ) {
+        let mut rule = automaton.rule;
+        let mut edges = automaton.edges;
+
+        if keyboard_input.just_pressed(KeyCode::ArrowRight) {
+            rule = rule.wrapping_add(1);
+        }
+        if keyboard_input.just_pressed(KeyCode::ArrowLeft) {
+            rule = rule.wrapping_sub(1);
+        }
+        if keyboard_input.just_pressed(KeyCode::KeyE) {
+            edges = match edges {
+                Edges::Wrap => Edges::Fixed(false),
+                Edges::Fixed(false) => Edges::Fixed(true),
+                Edges::Fixed(true) => Edges::Wrap,
+            };
+        }
+        if keyboard_input.just_pressed(KeyCode::KeyS) {
+            *initial_state = match *initial_state {
+                InitialState::Single => InitialState::Random,
+                InitialState::Random => InitialState::Single,
+            };
+        }
+
+        let restart = keyboard_input.any_just_pressed([
+            KeyCode::ArrowRight,
+            KeyCode::ArrowLeft,
+            KeyCode::KeyE,
+            KeyCode::KeyS,
+            KeyCode::KeyR,
+        ]);
+        if !restart {
+            return;
+        }
+
+        *automaton = match *initial_state {
+            InitialState::Single => Elementary::single(rule, edges, Self::WIDTH),
+            InitialState::Random => {
+                Elementary::new(rule, edges, (0..Self::WIDTH).map(|_| rng.bool()).collect())
+            }
+        };
+
+        if let Some(image) = images.get_mut(&canvas.0) {
+            image
+                .data
+                .chunks_exact_mut(4)
+                .for_each(|pixel| pixel.copy_from_slice(&Self::DEAD));
+        }
+    }
+
+    /// Scrolls the canvas up a row and draws the latest generation along the
+    /// bottom, stepping the automaton once that row has been drawn.
+    fn update(
+        time: Res<Time>,
+        mut timer: ResMut<StepTimer>,
+        mut automaton: ResMut<Elementary>,
+        canvas: Res<Canvas>,
+        mut images: ResMut<Assets<Image>>,
+    ) {
+        if !timer.0.tick(time.delta()).just_finished() {
+            return;
+        }
+        let Some(image) = images.get_mut(&canvas.0) else {
+            return;
+        };
+
+        let row_bytes = Self::WIDTH * 4;
+        image.data.copy_within(row_bytes.., 0);
+
+        let start = image.data.len() - row_bytes;
+        for (pixel, &alive) in image.data[start..]
+            .chunks_exact_mut(4)
+            .zip(automaton.cells())
+        {
+            pixel.copy_from_slice(if alive { &Self::ALIVE } else { &Self::DEAD });
+        }
+
+        automaton.step();
+    }
+
+    fn label(
+        automaton: Res<Elementary>,
+        initial_state: Res<InitialState>,
+        mut label: Query<&mut Text, With<RuleLabel>>,
+    ) {
+        if !automaton.is_changed() && !initial_state.is_changed() {
+            return;
+        }
+
+        let edges = match automaton.edges {
+            Edges::Wrap => "wrap",
+            Edges::Fixed(false) => "fixed dead",
+            Edges::Fixed(true) => "fixed alive",
+        };
+        let start = match *initial_state {
+            InitialState::Single => "single cell",
+            InitialState::Random => "random",
+        };
+        label.single_mut().sections[0].value =
+            format!("Rule {} | {edges} edges | {start} start", automaton.rule);
+    }
+}
@@ -0,0 +1,5 @@
+mod automaton;
+mod explorer;
+
+pub use automaton::*;
+pub use explorer::*;
@@ -0,0 +1,14 @@
+use bevy::prelude::*;
+use heep::Sketch;
+use wolfram::ElementaryPlugin;
+
+fn main() {
+    App::new()
+        .add_plugins(
+            Sketch::new("heep - Elementary Automata")
+                .with_world_height(ElementaryPlugin::GENERATIONS as f32)
+                .with_background(Color::BLACK),
+        )
+        .add_plugins(ElementaryPlugin)
+        .run();
+}
@@ -0,0 +1,96 @@
+use wolfram::{Edges, Elementary};
+
+const WIDTH: usize = 101;
+
+fn run(automaton: &mut Elementary, generations: usize) -> Vec<Vec<bool>> {
+    (0..generations)
+        .map(|_| {
+            let row = automaton.cells().to_vec();
+            automaton.step();
+            row
+        })
+        .collect()
+}
+
+fn parse(row: &str) -> Vec<bool> {
+    row.chars().map(|c| c == '#').collect()
+}
+
+#[test]
+fn rule_30_first_generations() {
+    let mut automaton = Elementary::single(30, Edges::Wrap, 9);
+    let rows = run(&mut automaton, 4);
+
+    assert_eq!(rows[0], parse("....#...."));
+    assert_eq!(rows[1], parse("...###..."));
+    assert_eq!(rows[2], parse("..##..#.."));
+    assert_eq!(rows[3], parse(".##.####."));
+}
+
+#[test]
+fn rule_30_center_column() {
+    // OEIS A051023.
+    let expected = [
+        1, 1, 0, 1, 1, 1, 0, 0, 1, 1, 0, 0, 0, 1, 0, 1, 1, 0, 0, 1, 0, 0, 1, 1, 1,
+    ];
+    let mut automaton = Elementary::single(30, Edges::Wrap, WIDTH);
+    let center: Vec<u8> = run(&mut automaton, expected.len())
+        .iter()
+        .map(|row| row[WIDTH / 2] as u8)
+        .collect();
+
+    assert_eq!(center, expected);
+}
+
+#[test]
+fn rule_90_draws_pascals_triangle_mod_2() {
+    let mut automaton = Elementary::single(90, Edges::Fixed(false), WIDTH);
+    let rows = run(&mut automaton, WIDTH / 2);
+
+    let mut pascal = vec![1u8];
+    for row in rows {
+        for (offset, &alive) in row.iter().enumerate() {
+            let k = offset as isize - (WIDTH / 2) as isize + (pascal.len() - 1) as isize;
+            let expected = k >= 0 && k % 2 == 0 && pascal.get(k as usize / 2) == Some(&1);
+            assert_eq!(alive, expected);
+        }
+
+        pascal = (0..=pascal.len())
+            .map(|i| {
+                let left = if i > 0 { pascal[i - 1] } else { 0 };
+                let right = pascal.get(i).copied().unwrap_or(0);
+                (left + right) % 2
+            })
+            .collect();
+    }
+}
+
+#[test]
+fn edges_wrap_or_hold_fixed_state() {
+    let cells = parse("#..");
+
+    let mut wrapped = Elementary::new(90, Edges::Wrap, cells.clone());
+    wrapped.step();
+    assert_eq!(wrapped.cells(), parse(".##"));
+
+    let mut fixed = Elementary::new(90, Edges::Fixed(false), cells.clone());
+    fixed.step();
+    assert_eq!(fixed.cells(), parse(".#."));
+
+    let mut alive = Elementary::new(90, Edges::Fixed(true), cells);
+    alive.step();
+    assert_eq!(alive.cells(), parse("###"));
+}
+
+#[test]
+fn every_rule_maps_its_own_bits() {
+    for rule in 0..=255u8 {
+        for neighbourhood in 0..8u8 {
+            let [left, center, right] = [4, 2, 1].map(|bit| neighbourhood & bit != 0);
+            assert_eq!(
+                Elementary::apply(rule, left, center, right),
+                rule & (1 << neighbourhood) != 0
+            );
+        }
+    }
+}